@@ -0,0 +1,236 @@
+use crate::runner::input_hash;
+use crate::solution::{visit_day, Solution, SolutionVisitor};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => f.pad("parse"),
+            Stage::Part1 => f.pad("part 1"),
+            Stage::Part2 => f.pad("part 2"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: 1,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub input_hash: String,
+    pub iterations: u32,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub measurements: Vec<Measurement>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: Stage,
+    pub baseline_median_ns: u64,
+    pub median_ns: u64,
+    // Relative change of the median in percent. Negative is faster than the baseline
+    pub change: f64,
+}
+
+// Benchmark parsing and both parts for each of the given days and inputs
+pub fn run(days: &[(u8, String)], config: BenchConfig) -> anyhow::Result<Report> {
+    let mut measurements = Vec::new();
+
+    for (day, input) in days {
+        let day_measurements = visit_day(
+            *day,
+            BenchDay {
+                day: *day,
+                input,
+                config,
+            },
+        )
+        .unwrap_or_else(|| Err(anyhow!("Day {} is not solved yet", day)))
+        .with_context(|| format!("Failed to benchmark day {}", day))?;
+        measurements.extend(day_measurements);
+    }
+
+    Ok(Report { measurements })
+}
+
+// Compare the medians of a report against a baseline. Stages benchmarked on another input are skipped
+pub fn compare(report: &Report, baseline: &Report) -> Vec<Comparison> {
+    report
+        .measurements
+        .iter()
+        .filter_map(|measurement| {
+            let base = baseline.measurements.iter().find(|base| {
+                base.day == measurement.day
+                    && base.stage == measurement.stage
+                    && base.input_hash == measurement.input_hash
+            })?;
+
+            Some(Comparison {
+                day: measurement.day,
+                stage: measurement.stage,
+                baseline_median_ns: base.median_ns,
+                median_ns: measurement.median_ns,
+                change: relative_change(base.median_ns, measurement.median_ns),
+            })
+        })
+        .collect()
+}
+
+pub fn load_report(path: &Path) -> anyhow::Result<Report> {
+    let json = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    serde_json::from_str(&json).with_context(|| format!("Failed to parse benchmark report {}", path.display()))
+}
+
+pub fn save_report(report: &Report, path: &Path) -> anyhow::Result<()> {
+    let json = serde_json::to_string_pretty(report)?;
+    std::fs::write(path, json + "\n").with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn relative_change(baseline: u64, current: u64) -> f64 {
+    if baseline == 0 {
+        return 0.0;
+    }
+
+    (current as f64 - baseline as f64) / baseline as f64 * 100.0
+}
+
+struct BenchDay<'a> {
+    day: u8,
+    input: &'a str,
+    config: BenchConfig,
+}
+
+impl SolutionVisitor for BenchDay<'_> {
+    type Output = anyhow::Result<Vec<Measurement>>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let config = self.config;
+        let parse = measure(config, || S::parse(black_box(self.input)).map(|_| ()))?;
+
+        let input = S::parse(self.input)?;
+        let part1 = measure(config, || {
+            S::part1(black_box(&input)).map(|answer| drop(black_box(answer)))
+        })?;
+        let part2 = measure(config, || {
+            S::part2(black_box(&input)).map(|answer| drop(black_box(answer)))
+        })?;
+
+        let hash = input_hash(self.input);
+        Ok(vec![
+            measurement(self.day, Stage::Parse, &hash, config, parse),
+            measurement(self.day, Stage::Part1, &hash, config, part1),
+            measurement(self.day, Stage::Part2, &hash, config, part2),
+        ])
+    }
+}
+
+// Time a closure for the configured number of iterations, returning the samples sorted
+fn measure<F>(config: BenchConfig, mut f: F) -> anyhow::Result<Vec<Duration>>
+where
+    F: FnMut() -> anyhow::Result<()>,
+{
+    for _ in 0..config.warmup {
+        f()?;
+    }
+
+    let mut samples = Vec::with_capacity(config.iterations as usize);
+    for _ in 0..config.iterations.max(1) {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+
+    samples.sort_unstable();
+    Ok(samples)
+}
+
+fn measurement(day: u8, stage: Stage, input_hash: &str, config: BenchConfig, samples: Vec<Duration>) -> Measurement {
+    let total: Duration = samples.iter().sum();
+
+    Measurement {
+        day,
+        stage,
+        input_hash: input_hash.to_owned(),
+        iterations: config.iterations.max(1),
+        min_ns: samples[0].as_nanos() as u64,
+        median_ns: samples[samples.len() / 2].as_nanos() as u64,
+        mean_ns: (total / samples.len() as u32).as_nanos() as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quick() -> BenchConfig {
+        BenchConfig {
+            warmup: 0,
+            iterations: 3,
+        }
+    }
+
+    #[test]
+    fn measures_parse_and_both_parts() {
+        let report = run(&[(1, String::from("1122"))], quick()).unwrap();
+        let stages: Vec<Stage> = report.measurements.iter().map(|m| m.stage).collect();
+
+        assert_eq!(stages, vec![Stage::Parse, Stage::Part1, Stage::Part2]);
+        assert!(report
+            .measurements
+            .iter()
+            .all(|m| m.iterations == 3 && m.min_ns <= m.median_ns));
+    }
+
+    #[test]
+    fn compares_against_a_baseline_on_the_same_input() {
+        let report = run(&[(9, String::from("{{}}"))], quick()).unwrap();
+        let mut baseline = report.clone();
+        baseline.measurements[0].median_ns = report.measurements[0].median_ns + 1_000_000;
+        baseline.measurements[1].input_hash = String::from("another input");
+
+        let comparisons = compare(&report, &baseline);
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(comparisons[0].change < 0.0);
+        assert_eq!(comparisons[1].stage, Stage::Part2);
+    }
+
+    #[test]
+    fn fails_on_unsolved_days() {
+        assert!(run(&[(12, String::new())], quick()).is_err());
+    }
+}
@@ -0,0 +1,696 @@
+use anyhow::Context;
+use aoc2017::bench::{self, BenchConfig, Comparison, Report};
+use aoc2017::day_01::simd::byte_captcha;
+use aoc2017::day_01::stream::{seek_captcha, stream_captcha};
+use aoc2017::day_01::{self, Alphabet, LengthPolicy, Offset, Parsing};
+use aoc2017::day_02::audit;
+use aoc2017::day_02::cell::{BigUint, Cell};
+use aoc2017::day_02::import::{self, BlankCells, ImportOptions};
+use aoc2017::day_02::pairs;
+use aoc2017::day_02::{self, NumberedRow, Strategy};
+use aoc2017::day_03;
+use aoc2017::day_03::lattice::{self, Cubic, Hexagonal, Lattice, Square};
+use aoc2017::day_03::memory::{GridMemory, HashMemory, Memory};
+use aoc2017::day_03::render::{self, Window};
+use aoc2017::day_03::rule::Rule;
+use aoc2017::fetch::{self, HttpBackend, InputCache};
+use aoc2017::input::{self, InputSource};
+use aoc2017::runner::{self, Answer, Part};
+use aoc2017::verify::{self, Outcome, Registry};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::fs::File;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2017 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or all days and print a summary table of the answers
+    Run {
+        /// The day to run. All days are run in sequence if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only run the given part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+
+        /// Read the puzzle input from a file, or from stdin if the path is `-`
+        #[arg(long, requires = "day", conflicts_with_all = ["inline", "profile"])]
+        input: Option<String>,
+
+        /// Use the given text as the puzzle input
+        #[arg(long, requires = "day", conflicts_with = "profile")]
+        inline: Option<String>,
+
+        /// Use the inputs cached for a profile by `aoc fetch`
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Benchmark parsing and both parts of one or all days on the bundled inputs
+    Bench {
+        /// The day to benchmark. All days are benchmarked if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Number of timed iterations per stage
+        #[arg(long, default_value_t = BenchConfig::default().iterations)]
+        iterations: u32,
+
+        /// Number of untimed iterations per stage before measuring
+        #[arg(long, default_value_t = BenchConfig::default().warmup)]
+        warmup: u32,
+
+        /// Save the report as JSON, e.g. to use as a baseline later
+        #[arg(long)]
+        save: Option<PathBuf>,
+
+        /// Compare the medians against a previously saved report
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Download puzzle inputs into the local cache, skipping the ones already cached
+    Fetch {
+        /// The day to fetch. All solved days are fetched if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Cache the inputs under this profile instead of next to the bundled inputs
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Where to fetch the inputs from
+        #[arg(long, default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// The adventofcode.com session cookie. Defaults to the AOC_SESSION environment variable
+        #[arg(long)]
+        session: Option<String>,
+    },
+    /// Solve the day 1 captcha with any offset on the bundled or given input
+    Captcha {
+        /// The offset to compare digits at: a number such as `1`, a fraction of the length such as `1/2`,
+        /// or a comma separated list of them
+        offset: Offset,
+
+        /// Read the captcha from a file, or from stdin if the path is `-`
+        #[arg(long, conflicts_with = "inline")]
+        input: Option<String>,
+
+        /// Use the given text as the captcha
+        #[arg(long)]
+        inline: Option<String>,
+
+        /// Read the captcha in chunks instead of loading it into memory. Offsets other than a fixed number
+        /// need a file to seek in, so stdin only supports those
+        #[arg(long)]
+        stream: bool,
+
+        /// Compare the digit bytes with SIMD instructions where the CPU supports them
+        #[arg(long, conflicts_with = "stream")]
+        simd: bool,
+
+        /// What to do when a fraction of the length is not a whole number of digits: reject, skip the last
+        /// digits or round the offset
+        #[arg(long, default_value = "reject")]
+        length_policy: LengthPolicy,
+
+        /// Only allow digits followed by whitespace, and report the position of anything else
+        #[arg(long)]
+        strict: bool,
+
+        /// The symbols of the captcha: a radix from 2 to 36 such as `16`, or symbols with the weight they add
+        /// to the sum such as `x=1,o=5`
+        #[arg(long, default_value = "10", conflicts_with_all = ["stream", "simd"])]
+        alphabet: Alphabet,
+    },
+    /// Compute the day 2 spreadsheet checksum with any row checksum strategy
+    Spreadsheet {
+        /// How to compute the checksum of each row: spread, division, divisible-pairs, median-spread or gcd
+        #[arg(long, default_value = "spread")]
+        strategy: Strategy,
+
+        /// Read the spreadsheet from a file, or from stdin if the path is `-`
+        #[arg(long, conflicts_with = "inline")]
+        input: Option<String>,
+
+        /// Use the given text as the spreadsheet
+        #[arg(long)]
+        inline: Option<String>,
+
+        /// Import the spreadsheet as a delimited export with this separator, such as `,` for CSV or `tab` for
+        /// TSV. Cells are separated by whitespace if omitted
+        #[arg(long, value_parser = parse_separator)]
+        separator: Option<char>,
+
+        /// The first row of the delimited export names the columns
+        #[arg(long, requires = "separator")]
+        header: bool,
+
+        /// What to do with blank cells in the delimited export: skip, zero or reject
+        #[arg(long, requires = "separator", default_value = "skip")]
+        blank_cells: BlankCells,
+
+        /// List every pair of values in each row that divide evenly instead of the checksum
+        #[arg(long, conflicts_with = "audit")]
+        pairs: bool,
+
+        /// Show how each row adds up to the checksum
+        #[arg(long)]
+        audit: bool,
+
+        /// How to show the audit
+        #[arg(long, value_enum, default_value_t = Format::Table, requires = "audit")]
+        format: Format,
+
+        /// How large the cells may be. Checksums that do not fit are reported rather than wrapping around
+        #[arg(long, value_enum, default_value_t = CellType::U32)]
+        cells: CellType,
+    },
+    /// Fill the day 3 spiral memory up to a value and draw it
+    Spiral {
+        /// Stop once a value at least this large is written
+        target: u32,
+
+        /// The value to write in each square, such as `prev + 1` for the square numbers of part 1 or
+        /// `sum(neighbors8)` for the neighbour sums of part 2
+        #[arg(long, default_value = "prev + 1")]
+        rule: Rule,
+
+        /// Also stop after writing this many squares, for rules that never reach the target
+        #[arg(long, default_value_t = 10_000_000)]
+        squares: usize,
+
+        /// Only draw the squares this many steps from the access port. Everything written is drawn if omitted
+        #[arg(long)]
+        radius: Option<i32>,
+
+        /// Write an SVG image to this file instead of printing the grid
+        #[arg(long)]
+        svg: Option<PathBuf>,
+
+        /// Where to keep the values: a grid growing around the access port, or a hash map
+        #[arg(long, value_enum, default_value_t = MemoryBackend::Grid)]
+        memory: MemoryBackend,
+    },
+    /// Solve day 3 with the spiral walked on a square, hexagonal or cubic lattice
+    Lattice {
+        /// The square number for part 1, and the value to exceed for part 2
+        input: u32,
+
+        /// The lattice to walk. Distances are Manhattan distances on the square and cubic lattices, and the
+        /// fewest steps between hexagons on the hexagonal one
+        #[arg(long, value_enum, default_value_t = LatticeType::Square)]
+        lattice: LatticeType,
+    },
+    /// Run every solver against every input in the answer registry and report mismatches
+    Verify {
+        /// The answer registry to verify. Defaults to the one for the bundled puzzle inputs
+        #[arg(long, default_value = verify::DEFAULT_REGISTRY)]
+        registry: PathBuf,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CellType {
+    U32,
+    U64,
+    U128,
+    Big,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum MemoryBackend {
+    Grid,
+    Hash,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LatticeType {
+    Square,
+    Hex,
+    Cubic,
+}
+
+// What `aoc spreadsheet` shows
+#[derive(Clone, Copy)]
+enum SpreadsheetReport {
+    Checksum,
+    Pairs,
+    Audit(Format),
+}
+
+// How `aoc captcha` reads and solves the captcha
+#[derive(Clone, Copy)]
+enum CaptchaMethod {
+    Parse,
+    Stream,
+    Simd,
+}
+
+fn main() -> anyhow::Result<()> {
+    match Cli::parse().command {
+        Command::Run {
+            day,
+            part,
+            input,
+            inline,
+            profile,
+            format,
+        } => {
+            let source = match (input, inline, profile) {
+                (Some(path), _, _) => InputSource::from_path(&path),
+                (_, Some(text), _) => InputSource::Inline(text),
+                (_, _, Some(profile)) => InputSource::Profile(profile),
+                _ => InputSource::Bundled,
+            };
+
+            run(day, part, &source, format)
+        }
+        Command::Bench {
+            day,
+            iterations,
+            warmup,
+            save,
+            baseline,
+        } => benchmark(day, BenchConfig { warmup, iterations }, save, baseline),
+        Command::Fetch {
+            day,
+            profile,
+            base_url,
+            session,
+        } => fetch_inputs(day, profile.as_deref(), &base_url, session),
+        Command::Captcha {
+            offset,
+            input,
+            inline,
+            stream,
+            simd,
+            length_policy,
+            strict,
+            alphabet,
+        } => {
+            let source = match (input, inline) {
+                (Some(path), _) => InputSource::from_path(&path),
+                (_, Some(text)) => InputSource::Inline(text),
+                _ => InputSource::Bundled,
+            };
+
+            let parsing = match strict {
+                true => Parsing::Strict,
+                false => Parsing::Lenient,
+            };
+
+            let method = match (stream, simd) {
+                (true, _) => CaptchaMethod::Stream,
+                (_, true) => CaptchaMethod::Simd,
+                _ => CaptchaMethod::Parse,
+            };
+
+            solve_captcha(&offset, &source, method, length_policy, parsing, &alphabet)
+        }
+        Command::Spreadsheet {
+            strategy,
+            input,
+            inline,
+            separator,
+            header,
+            blank_cells,
+            pairs,
+            audit,
+            format,
+            cells,
+        } => {
+            let source = match (input, inline) {
+                (Some(path), _) => InputSource::from_path(&path),
+                (_, Some(text)) => InputSource::Inline(text),
+                _ => InputSource::Bundled,
+            };
+
+            let text = input::load(2, &source)?;
+            let options = separator.map(|separator| ImportOptions {
+                separator,
+                header,
+                blank_cells,
+            });
+            let report = match (pairs, audit) {
+                (true, _) => SpreadsheetReport::Pairs,
+                (_, true) => SpreadsheetReport::Audit(format),
+                _ => SpreadsheetReport::Checksum,
+            };
+
+            match cells {
+                CellType::U32 => report_spreadsheet::<u32>(&text, options.as_ref(), &strategy, report),
+                CellType::U64 => report_spreadsheet::<u64>(&text, options.as_ref(), &strategy, report),
+                CellType::U128 => report_spreadsheet::<u128>(&text, options.as_ref(), &strategy, report),
+                CellType::Big => report_spreadsheet::<BigUint>(&text, options.as_ref(), &strategy, report),
+            }
+        }
+        Command::Spiral {
+            target,
+            rule,
+            squares,
+            radius,
+            svg,
+            memory,
+        } => {
+            let value_fn = rule.into_value_fn();
+            let done = |value, memory: &dyn Memory| value >= target || memory.len() >= squares;
+            let memory: Box<dyn Memory> = match memory {
+                MemoryBackend::Grid => Box::new(day_03::populate_memory_until(
+                    day_03::init_memory::<Square, GridMemory>(),
+                    &*value_fn,
+                    |value, memory| done(value, memory),
+                )),
+                MemoryBackend::Hash => Box::new(day_03::populate_memory_until(
+                    day_03::init_memory::<Square, HashMemory>(),
+                    &*value_fn,
+                    |value, memory| done(value, memory),
+                )),
+            };
+
+            // The target is the last square written
+            let last = memory
+                .points()
+                .map(|(point, _)| point)
+                .max_by_key(|point| day_03::point_to_square(*point).ok());
+            let window = match radius {
+                Some(radius) => Window::around((0, 0), radius),
+                None => Window::covering(&*memory),
+            };
+
+            match svg {
+                Some(path) => std::fs::write(&path, render::render_svg(&*memory, &window, last))
+                    .with_context(|| format!("Failed to write {}", path.display()))?,
+                None => print!("{}", render::render_text(&*memory, &window, last)),
+            }
+            Ok(())
+        }
+        Command::Lattice { input, lattice } => match lattice {
+            LatticeType::Square => solve_lattice::<Square>(input),
+            LatticeType::Hex => solve_lattice::<Hexagonal>(input),
+            LatticeType::Cubic => solve_lattice::<Cubic>(input),
+        },
+        Command::Verify { registry } => verify_registry(&registry),
+    }
+}
+
+fn solve_lattice<L: Lattice>(input: u32) -> anyhow::Result<()> {
+    println!("Part 1: {}", lattice::lattice_distance::<L>(input)?);
+    println!("Part 2: {}", lattice::lattice_first_value_exceeding::<L>(input)?);
+    Ok(())
+}
+
+fn solve_captcha(
+    offset: &Offset,
+    source: &InputSource,
+    method: CaptchaMethod,
+    policy: LengthPolicy,
+    parsing: Parsing,
+    alphabet: &Alphabet,
+) -> anyhow::Result<()> {
+    let sum = match (method, source) {
+        (CaptchaMethod::Parse, source) => {
+            let symbols = alphabet.parse(&input::load(1, source)?, parsing)?;
+            day_01::alphabet_captcha(&symbols, alphabet, offset, policy)?
+        }
+        (CaptchaMethod::Simd, source) => byte_captcha(input::load(1, source)?.as_bytes(), offset, policy, parsing)?,
+        (CaptchaMethod::Stream, InputSource::Stdin) => match offset {
+            Offset::Fixed(offset) => stream_captcha(std::io::stdin().lock(), *offset, parsing)?,
+            _ => return Err(anyhow::anyhow!("Streaming from stdin only supports a fixed offset")),
+        },
+        (CaptchaMethod::Stream, InputSource::File(path)) => seek_captcha(open(path)?, offset, policy, parsing)?,
+        (CaptchaMethod::Stream, InputSource::Bundled) => {
+            seek_captcha(open(&input::bundled_path(1))?, offset, policy, parsing)?
+        }
+        (CaptchaMethod::Stream, source) => {
+            seek_captcha(Cursor::new(input::load(1, source)?), offset, policy, parsing)?
+        }
+    };
+
+    println!("{}", sum);
+    Ok(())
+}
+
+fn open(path: &Path) -> anyhow::Result<File> {
+    File::open(path).with_context(|| format!("Failed to open {}", path.display()))
+}
+
+fn run(day: Option<u8>, part: Option<Part>, source: &InputSource, format: Format) -> anyhow::Result<()> {
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => runner::DAYS.to_vec(),
+    };
+
+    let parts: Vec<Part> = match part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    };
+
+    let mut answers: Vec<Answer> = Vec::new();
+    for day in days {
+        let puzzle_input = input::load(day, source)?;
+
+        for part in &parts {
+            answers.push(runner::solve(day, *part, &puzzle_input)?);
+        }
+    }
+
+    match format {
+        Format::Table => print!("{}", summary_table(&answers)),
+        Format::Json => println!("{}", serde_json::to_string_pretty(&answers)?),
+    }
+
+    Ok(())
+}
+
+fn benchmark(
+    day: Option<u8>,
+    config: BenchConfig,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+) -> anyhow::Result<()> {
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => runner::DAYS.to_vec(),
+    };
+
+    let inputs = days
+        .into_iter()
+        .map(|day| input::load_bundled(day).map(|input| (day, input)))
+        .collect::<anyhow::Result<Vec<(u8, String)>>>()?;
+
+    let report = bench::run(&inputs, config)?;
+    let comparisons = match baseline {
+        Some(path) => bench::compare(&report, &bench::load_report(&path)?),
+        None => Vec::new(),
+    };
+
+    print!("{}", bench_table(&report, &comparisons));
+
+    if let Some(path) = save {
+        bench::save_report(&report, &path)?;
+    }
+
+    Ok(())
+}
+
+fn bench_table(report: &Report, comparisons: &[Comparison]) -> String {
+    let mut table = String::from("Day | Stage  |       Median |          Min |         Mean | Baseline\n");
+    table.push_str("----+--------+--------------+--------------+--------------+---------\n");
+
+    for m in &report.measurements {
+        let change = comparisons
+            .iter()
+            .find(|c| c.day == m.day && c.stage == m.stage)
+            .map(|c| format!("{:+.1}%", c.change))
+            .unwrap_or_else(|| String::from("-"));
+
+        table.push_str(&format!(
+            "{:>3} | {:<6} | {:>9.3} ms | {:>9.3} ms | {:>9.3} ms | {}\n",
+            m.day,
+            m.stage,
+            m.median_ns as f64 / 1e6,
+            m.min_ns as f64 / 1e6,
+            m.mean_ns as f64 / 1e6,
+            change
+        ));
+    }
+
+    table
+}
+
+fn fetch_inputs(
+    day: Option<u8>,
+    profile: Option<&str>,
+    base_url: &str,
+    session: Option<String>,
+) -> anyhow::Result<()> {
+    let days: Vec<u8> = match day {
+        Some(day) => vec![day],
+        None => runner::DAYS.to_vec(),
+    };
+
+    let session = match session {
+        Some(session) => session,
+        None => std::env::var("AOC_SESSION")
+            .map_err(|_| anyhow::anyhow!("No session given. Pass --session or set AOC_SESSION"))?,
+    };
+
+    let backend = HttpBackend::new(base_url, &session);
+    let cache = InputCache::default();
+
+    for day in days {
+        cache.get_or_fetch(profile, day, &backend)?;
+        println!("{}", cache.path(profile, day)?.display());
+    }
+
+    Ok(())
+}
+
+fn verify_registry(path: &Path) -> anyhow::Result<()> {
+    let registry = Registry::load(path)?;
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let outcomes = verify::verify(&registry, base_dir);
+
+    print!("{}", verify_table(&outcomes));
+
+    let mismatches = outcomes.iter().filter(|o| !o.is_match()).count();
+    match mismatches {
+        0 => Ok(()),
+        _ => Err(anyhow::anyhow!(
+            "{} of {} answers did not match",
+            mismatches,
+            outcomes.len()
+        )),
+    }
+}
+
+fn verify_table(outcomes: &[Outcome]) -> String {
+    let input_width = outcomes
+        .iter()
+        .map(|o| o.input.display().to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max("Input".len());
+    let mut table = format!("Day | Part | {:<width$} | Result\n", "Input", width = input_width);
+    table.push_str(&format!("----+------+-{}-+-------\n", "-".repeat(input_width)));
+
+    for outcome in outcomes {
+        let result = match &outcome.actual {
+            Ok(_) if outcome.is_match() => String::from("ok"),
+            Ok(actual) => format!("MISMATCH: expected {}, got {}", outcome.expected, actual),
+            Err(error) => format!("ERROR: {}", error),
+        };
+
+        table.push_str(&format!(
+            "{:>3} | {:>4} | {:<width$} | {}\n",
+            outcome.day,
+            outcome.part,
+            outcome.input.display().to_string(),
+            result,
+            width = input_width
+        ));
+    }
+
+    table
+}
+
+fn summary_table(answers: &[Answer]) -> String {
+    let answer_width = answers
+        .iter()
+        .map(|a| a.answer.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+    let mut table = format!("Day | Part | {:<width$} | Time\n", "Answer", width = answer_width);
+    table.push_str(&format!("----+------+-{}-+---------\n", "-".repeat(answer_width)));
+
+    for answer in answers {
+        table.push_str(&format!(
+            "{:>3} | {:>4} | {:<width$} | {:.3} ms\n",
+            answer.day,
+            answer.part,
+            answer.answer,
+            answer.elapsed.as_secs_f64() * 1000.0,
+            width = answer_width
+        ));
+    }
+
+    table
+}
+
+fn report_spreadsheet<T: Cell>(
+    text: &str,
+    options: Option<&ImportOptions>,
+    strategy: &Strategy,
+    report: SpreadsheetReport,
+) -> anyhow::Result<()> {
+    let spreadsheet: Vec<NumberedRow<T>> = match options {
+        Some(options) => import::import_cells(text, options)?.rows,
+        None => day_02::parse_numbered_cells(text)?,
+    };
+
+    match report {
+        SpreadsheetReport::Checksum => {
+            let cells: Vec<Vec<T>> = spreadsheet.into_iter().map(|row| row.cells).collect();
+            println!("{}", day_02::spreadsheet_checksum(&cells, strategy)?)
+        }
+        SpreadsheetReport::Pairs => print_divisible_pairs(&spreadsheet),
+        SpreadsheetReport::Audit(Format::Table) => print!("{}", audit::spreadsheet_audit(&spreadsheet, strategy)?),
+        SpreadsheetReport::Audit(Format::Json) => println!(
+            "{}",
+            serde_json::to_string_pretty(&audit::spreadsheet_audit(&spreadsheet, strategy)?)?
+        ),
+    }
+    Ok(())
+}
+
+fn print_divisible_pairs<T: Cell>(spreadsheet: &[NumberedRow<T>]) {
+    for row in spreadsheet {
+        for pair in pairs::divisible_pairs(&row.cells) {
+            println!(
+                "row {}, columns {} and {}: {} / {} = {}",
+                row.line,
+                row.columns[pair.dividend_index],
+                row.columns[pair.divisor_index],
+                pair.dividend,
+                pair.divisor,
+                pair.quotient()
+            );
+        }
+    }
+}
+
+fn parse_separator(value: &str) -> Result<char, String> {
+    let mut chars = value.chars();
+    match (value, chars.next(), chars.next()) {
+        ("tab", _, _) | ("\\t", _, _) => Ok('\t'),
+        (_, Some(separator), None) => Ok(separator),
+        _ => Err(String::from("separator must be a single character or `tab`")),
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(String::from("part must be 1 or 2")),
+    }
+}
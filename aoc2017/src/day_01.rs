@@ -0,0 +1,512 @@
+use crate::solution::Solution;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+pub mod simd;
+pub mod stream;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        inverse_capcha(input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        inverse_circular_capcha(input)
+    }
+}
+
+// How far ahead around the circle each digit is compared with
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Offset {
+    Fixed(usize),
+    // A fraction of the captcha length, as numerator and denominator
+    Fraction(usize, usize),
+    // A digit counts once if it matches the digit at any of the offsets
+    List(Vec<Offset>),
+}
+
+impl Offset {
+    // The distinct distances ahead this offset compares with, for a captcha of the given length
+    pub fn distances(&self, length: usize) -> anyhow::Result<Vec<usize>> {
+        self.resolve(length, LengthPolicy::Reject)
+            .map(|(_, distances)| distances)
+    }
+
+    // The number of digits to use from a captcha of the given length, and the distinct distances ahead to compare
+    // them with, applying the policy when a fraction of the length is not a whole number of digits
+    pub fn resolve(&self, length: usize, policy: LengthPolicy) -> anyhow::Result<(usize, Vec<usize>)> {
+        let used = match policy {
+            LengthPolicy::Skip => length - length % self.length_multiple(),
+            _ => length,
+        };
+
+        if used == 0 {
+            return Err(anyhow!(
+                "The captcha has {} digits, which is too few for the offset {}",
+                length,
+                self
+            ));
+        }
+
+        let mut distances = self.distances_in(used, policy)?;
+        distances.sort_unstable();
+        distances.dedup();
+        Ok((used, distances))
+    }
+
+    fn distances_in(&self, length: usize, policy: LengthPolicy) -> anyhow::Result<Vec<usize>> {
+        match self {
+            Offset::Fixed(offset) => Ok(vec![offset % length]),
+            Offset::Fraction(_, 0) => Err(anyhow!("The offset {} has a zero denominator", self)),
+            Offset::Fraction(numerator, denominator) => {
+                let whole = (length * numerator).is_multiple_of(*denominator);
+                match policy {
+                    LengthPolicy::Round if !whole => {
+                        // Round half way up, so 1/2 of 5 digits is 3
+                        Ok(vec![
+                            ((2 * length * numerator + denominator) / (2 * denominator)) % length,
+                        ])
+                    }
+                    _ if !whole => Err(anyhow!(
+                        "The offset {} needs a captcha length divisible by {}, but it has {} digits",
+                        self,
+                        self.length_multiple(),
+                        length
+                    )),
+                    _ => Ok(vec![(length * numerator / denominator) % length]),
+                }
+            }
+            Offset::List(offsets) => Ok(offsets
+                .iter()
+                .map(|offset| offset.distances_in(length, policy))
+                .collect::<anyhow::Result<Vec<Vec<usize>>>>()?
+                .concat()),
+        }
+    }
+
+    // The captcha length must be a multiple of this for the offset to land on a whole digit
+    fn length_multiple(&self) -> usize {
+        match self {
+            Offset::Fixed(_) | Offset::Fraction(_, 0) => 1,
+            Offset::Fraction(numerator, denominator) => denominator / gcd(*numerator, *denominator),
+            Offset::List(offsets) => offsets
+                .iter()
+                .map(Offset::length_multiple)
+                .fold(1, |multiple, other| multiple / gcd(multiple, other) * other),
+        }
+    }
+}
+
+impl fmt::Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Offset::Fixed(offset) => write!(f, "{}", offset),
+            Offset::Fraction(numerator, denominator) => write!(f, "{}/{}", numerator, denominator),
+            Offset::List(offsets) => {
+                let offsets: Vec<String> = offsets.iter().map(Offset::to_string).collect();
+                write!(f, "{}", offsets.join(","))
+            }
+        }
+    }
+}
+
+// Offsets are written as `3`, `1/2` or a comma separated list such as `1,1/3,2/3`
+impl FromStr for Offset {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Offset> {
+        if s.contains(',') {
+            return Ok(Offset::List(
+                s.split(',').map(str::parse).collect::<anyhow::Result<_>>()?,
+            ));
+        }
+
+        let number = |n: &str| {
+            n.trim()
+                .parse::<usize>()
+                .map_err(|_| anyhow!("Expected an offset such as 1, 1/2 or 1,1/2, found {:?}", s))
+        };
+
+        match s.split_once('/') {
+            Some((numerator, denominator)) => Ok(Offset::Fraction(number(numerator)?, number(denominator)?)),
+            None => Ok(Offset::Fixed(number(s)?)),
+        }
+    }
+}
+
+// What to do when a fractional offset does not land on a whole digit, such as 1/2 of an odd length captcha
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LengthPolicy {
+    // Fail to solve the captcha
+    #[default]
+    Reject,
+    // Leave out as few digits from the end as it takes for the offset to land on a whole digit
+    Skip,
+    // Round the offset to the nearest whole digit, rounding half way up
+    Round,
+}
+
+impl FromStr for LengthPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<LengthPolicy> {
+        match s {
+            "reject" => Ok(LengthPolicy::Reject),
+            "skip" => Ok(LengthPolicy::Skip),
+            "round" => Ok(LengthPolicy::Round),
+            _ => Err(anyhow!("Expected reject, skip or round, found {:?}", s)),
+        }
+    }
+}
+
+// Lenient parsing ignores anything that is not a digit. Strict parsing only allows digits, followed by whitespace
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Parsing {
+    #[default]
+    Lenient,
+    Strict,
+}
+
+// A character strict parsing does not allow, at its byte position from the start of the input
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InvalidDigit {
+    pub position: u64,
+    pub found: char,
+}
+
+impl fmt::Display for InvalidDigit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Expected a digit at byte {}, found {:?}", self.position, self.found)
+    }
+}
+
+impl std::error::Error for InvalidDigit {}
+
+// The symbols a captcha is written in, and what each one adds to the sum when it matches
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Alphabet {
+    // The digits of a radix from 2 to 36 in either case, weighing their value
+    Radix(u32),
+    // Custom symbols, each with its own weight
+    Symbols(Vec<(char, u32)>),
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::Radix(10)
+    }
+}
+
+impl Alphabet {
+    // Where the character is in the alphabet, or None if it is not a symbol of it
+    pub fn index(&self, c: char) -> Option<u32> {
+        match self {
+            Alphabet::Radix(radix) if (2..=36).contains(radix) => c.to_digit(*radix),
+            Alphabet::Radix(_) => None,
+            Alphabet::Symbols(symbols) => symbols
+                .iter()
+                .position(|(symbol, _)| *symbol == c)
+                .map(|index| index as u32),
+        }
+    }
+
+    // The weight of the symbol at the given index
+    pub fn weight(&self, index: u32) -> u32 {
+        match self {
+            Alphabet::Radix(_) => index,
+            Alphabet::Symbols(symbols) => symbols[index as usize].1,
+        }
+    }
+
+    // Parse a captcha into the indexes of its symbols, where strict parsing reports the first character that is
+    // not allowed
+    pub fn parse(&self, input: &str, parsing: Parsing) -> Result<Vec<u32>, InvalidDigit> {
+        let mut filter = DigitFilter::new(self, parsing);
+        let mut digits = Vec::with_capacity(input.len());
+
+        for (position, c) in input.char_indices() {
+            if let Some(digit) = filter.digit(position as u64, c)? {
+                digits.push(digit);
+            }
+        }
+
+        Ok(digits)
+    }
+}
+
+// Alphabets are written as a radix such as `16`, or as symbols with their weights such as `x=1,o=5`
+impl FromStr for Alphabet {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Alphabet> {
+        if let Ok(radix) = s.parse::<u32>() {
+            return match radix {
+                2..=36 => Ok(Alphabet::Radix(radix)),
+                _ => Err(anyhow!("The radix must be from 2 to 36, found {}", radix)),
+            };
+        }
+
+        let mut symbols: Vec<(char, u32)> = Vec::new();
+        for symbol in s.split(',') {
+            let mut chars = symbol.chars();
+            let parsed = match (chars.next(), chars.next(), chars.as_str().parse::<u32>()) {
+                (Some(c), Some('='), Ok(weight)) => Some((c, weight)),
+                _ => None,
+            };
+
+            match parsed {
+                Some((c, _)) if symbols.iter().any(|(other, _)| *other == c) => {
+                    return Err(anyhow!("The symbol {:?} is in the alphabet more than once", c))
+                }
+                Some(symbol) => symbols.push(symbol),
+                None => {
+                    return Err(anyhow!(
+                        "Expected a radix such as 16, or symbols with weights such as x=1,o=5, found {:?}",
+                        s
+                    ))
+                }
+            }
+        }
+
+        Ok(Alphabet::Symbols(symbols))
+    }
+}
+
+// Checks characters one at a time against the alphabet and parsing rules, so input can be validated as it
+// streams past
+#[derive(Clone, Copy, Debug)]
+pub struct DigitFilter<'a> {
+    alphabet: &'a Alphabet,
+    parsing: Parsing,
+    trailing_whitespace: bool,
+}
+
+impl<'a> DigitFilter<'a> {
+    pub fn new(alphabet: &'a Alphabet, parsing: Parsing) -> DigitFilter<'a> {
+        DigitFilter {
+            alphabet,
+            parsing,
+            trailing_whitespace: false,
+        }
+    }
+
+    // The index of the character in the alphabet, None if it is skipped, or an error if strict parsing rejects it.
+    // The position is the byte offset of the character, to report errors with
+    pub fn digit(&mut self, position: u64, c: char) -> Result<Option<u32>, InvalidDigit> {
+        let invalid = InvalidDigit { position, found: c };
+
+        match (self.parsing, self.alphabet.index(c)) {
+            (Parsing::Strict, Some(_)) if self.trailing_whitespace => Err(invalid),
+            (_, Some(digit)) => Ok(Some(digit)),
+            (Parsing::Strict, None) if c.is_whitespace() && position > 0 => {
+                self.trailing_whitespace = true;
+                Ok(None)
+            }
+            (Parsing::Strict, None) => Err(invalid),
+            (Parsing::Lenient, None) => Ok(None),
+        }
+    }
+}
+
+// Sum the digits that match the digit the given offset ahead of them around the circle
+pub fn captcha(digits: &[u32], offset: &Offset) -> anyhow::Result<u32> {
+    captcha_with(digits, offset, LengthPolicy::Reject)
+}
+
+// Like `captcha`, applying the policy when a fraction of the length is not a whole number of digits
+pub fn captcha_with(digits: &[u32], offset: &Offset, policy: LengthPolicy) -> anyhow::Result<u32> {
+    let sum = sum_matching(digits, offset, policy, |digit| digit)?;
+    u32::try_from(sum).map_err(|_| anyhow!("The captcha sum {} does not fit in 32 bits", sum))
+}
+
+// Solve a captcha parsed with `Alphabet::parse`, summing the weights of the matching symbols
+pub fn alphabet_captcha(
+    symbols: &[u32],
+    alphabet: &Alphabet,
+    offset: &Offset,
+    policy: LengthPolicy,
+) -> anyhow::Result<u64> {
+    sum_matching(symbols, offset, policy, |symbol| alphabet.weight(symbol))
+}
+
+// Sum the weights of the symbols that match the symbol the given offset ahead of them around the circle
+fn sum_matching<F>(symbols: &[u32], offset: &Offset, policy: LengthPolicy, weight: F) -> anyhow::Result<u64>
+where
+    F: Fn(u32) -> u32,
+{
+    if symbols.is_empty() {
+        return Err(anyhow!("The captcha contains no digits"));
+    }
+
+    let (used, distances) = offset.resolve(symbols.len(), policy)?;
+    let symbols = &symbols[..used];
+
+    Ok(symbols
+        .iter()
+        .enumerate()
+        .filter(|(index, symbol)| {
+            distances
+                .iter()
+                .any(|distance| symbols[(index + distance) % symbols.len()] == **symbol)
+        })
+        .map(|(_, symbol)| u64::from(weight(*symbol)))
+        .sum())
+}
+
+/* Solver for D1P1 */
+pub fn inverse_capcha(number_vec: &[u32]) -> anyhow::Result<u32> {
+    captcha(number_vec, &Offset::Fixed(1))
+}
+
+/* Solver for D1P2 */
+pub fn inverse_circular_capcha(number_vec: &[u32]) -> anyhow::Result<u32> {
+    captcha(number_vec, &Offset::Fraction(1, 2))
+}
+
+/* Create a vector of u32s from a string input */
+pub fn parse_input(string_input: &str) -> Vec<u32> {
+    string_input
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<u32>>()
+}
+
+// Parse the decimal digits of a captcha, where strict parsing reports the first character that is not allowed
+pub fn parse_digits(input: &str, parsing: Parsing) -> Result<Vec<u32>, InvalidDigit> {
+    Alphabet::default().parse(input, parsing)
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a.max(1),
+        _ => gcd(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_captchas_it_cannot_solve() {
+        assert!(inverse_capcha(&parse_input("")).is_err());
+        assert!(inverse_circular_capcha(&parse_input("12131")).is_err());
+    }
+
+    #[test]
+    fn solves_captchas_with_any_offset() {
+        let digits = parse_input("123123");
+        assert_eq!(captcha(&digits, &Offset::Fixed(3)).unwrap(), 12);
+        assert_eq!(captcha(&digits, &Offset::Fixed(9)).unwrap(), 12);
+        assert_eq!(captcha(&digits, &Offset::Fixed(6)).unwrap(), 12);
+        assert_eq!(captcha(&digits, &Offset::Fraction(1, 3)).unwrap(), 0);
+        assert!(captcha(&digits, &Offset::Fraction(1, 4)).is_err());
+        assert!(captcha(&digits, &Offset::Fraction(1, 0)).is_err());
+
+        let digits = parse_input("1122");
+        let either = Offset::List(vec![Offset::Fixed(1), Offset::Fraction(3, 4)]);
+        assert_eq!(captcha(&digits, &either).unwrap(), 6);
+    }
+
+    #[test]
+    fn parses_offsets() {
+        assert_eq!("3".parse::<Offset>().unwrap(), Offset::Fixed(3));
+        assert_eq!("1/2".parse::<Offset>().unwrap(), Offset::Fraction(1, 2));
+        assert_eq!(
+            "1,2/3".parse::<Offset>().unwrap(),
+            Offset::List(vec![Offset::Fixed(1), Offset::Fraction(2, 3)])
+        );
+        assert_eq!(
+            Offset::List(vec![Offset::Fixed(1), Offset::Fraction(2, 3)]).to_string(),
+            "1,2/3"
+        );
+        assert!("half".parse::<Offset>().is_err());
+    }
+
+    #[test]
+    fn applies_length_policies() {
+        let digits = parse_input("12131");
+        let half = Offset::Fraction(1, 2);
+
+        assert!(captcha_with(&digits, &half, LengthPolicy::Reject).is_err());
+        // Skipping the last digit leaves 1213, where both 1s match
+        assert_eq!(captcha_with(&digits, &half, LengthPolicy::Skip).unwrap(), 2);
+        // Rounding compares each digit with the one 3 ahead, and only the 1s at 2 and 4 match
+        assert_eq!(captcha_with(&digits, &half, LengthPolicy::Round).unwrap(), 2);
+        assert_eq!(captcha_with(&parse_input("1"), &half, LengthPolicy::Round).unwrap(), 1);
+        assert!(captcha_with(&parse_input("1"), &half, LengthPolicy::Skip).is_err());
+
+        // 2/4 is the same as 1/2, so one digit is enough to skip
+        let thirds = Offset::List(vec![Offset::Fraction(2, 4), Offset::Fraction(1, 3)]);
+        assert_eq!(thirds.resolve(13, LengthPolicy::Skip).unwrap(), (12, vec![4, 6]));
+    }
+
+    #[test]
+    fn parses_strictly_or_leniently() {
+        assert_eq!(parse_digits("1a2\n", Parsing::Lenient).unwrap(), vec![1, 2]);
+        assert_eq!(parse_digits("12 \n", Parsing::Strict).unwrap(), vec![1, 2]);
+        assert_eq!(
+            parse_digits("12a3", Parsing::Strict),
+            Err(InvalidDigit {
+                position: 2,
+                found: 'a'
+            })
+        );
+        assert_eq!(
+            parse_digits("12\n3", Parsing::Strict),
+            Err(InvalidDigit {
+                position: 3,
+                found: '3'
+            })
+        );
+        assert_eq!(
+            parse_digits(" 12", Parsing::Strict).unwrap_err().to_string(),
+            "Expected a digit at byte 0, found ' '"
+        );
+    }
+
+    #[test]
+    fn solves_captchas_in_other_alphabets() {
+        let hex: Alphabet = "16".parse().unwrap();
+        let symbols = hex.parse("ffA1Aa", Parsing::Strict).unwrap();
+        assert_eq!(symbols, vec![15, 15, 10, 1, 10, 10]);
+        assert_eq!(
+            alphabet_captcha(&symbols, &hex, &Offset::Fixed(1), LengthPolicy::Reject).unwrap(),
+            25
+        );
+        assert!(hex.parse("fg", Parsing::Strict).is_err());
+
+        // Symbols weighing the same do not match each other
+        let custom: Alphabet = "x=1,o=5,★=1".parse().unwrap();
+        let symbols = custom.parse("xo★★ox", Parsing::Lenient).unwrap();
+        assert_eq!(symbols, vec![0, 1, 2, 2, 1, 0]);
+        assert_eq!(
+            alphabet_captcha(&symbols, &custom, &Offset::Fixed(1), LengthPolicy::Reject).unwrap(),
+            2
+        );
+        assert_eq!(
+            custom.parse("xo★!", Parsing::Strict),
+            Err(InvalidDigit {
+                position: 5,
+                found: '!'
+            })
+        );
+
+        assert!("37".parse::<Alphabet>().is_err());
+        assert!("x=1,x=2".parse::<Alphabet>().is_err());
+        assert!("x=heavy".parse::<Alphabet>().is_err());
+    }
+}
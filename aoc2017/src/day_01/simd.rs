@@ -0,0 +1,199 @@
+use super::{InvalidDigit, LengthPolicy, Offset, Parsing};
+use std::borrow::Cow;
+
+// Solve a captcha straight from its ASCII digit bytes, comparing many digits per instruction where the CPU allows.
+// Offsets with a single distance take the vectorized path, while a list of several distances is compared byte
+// by byte
+pub fn byte_captcha(input: &[u8], offset: &Offset, policy: LengthPolicy, parsing: Parsing) -> anyhow::Result<u64> {
+    let digits = digit_bytes(input, parsing)?;
+    if digits.is_empty() {
+        return Err(anyhow!("The captcha contains no digits"));
+    }
+
+    let (used, distances) = offset.resolve(digits.len(), policy)?;
+    let digits = &digits[..used];
+
+    match distances[..] {
+        [distance] => {
+            // Digits before the wrap compare with the ones `distance` ahead, and the rest with the start
+            let (before_wrap, after_wrap) = digits.split_at(used - distance);
+            Ok(sum_equal(before_wrap, &digits[distance..]) + sum_equal(after_wrap, &digits[..distance]))
+        }
+        _ => Ok(digits
+            .iter()
+            .enumerate()
+            .filter(|(index, digit)| {
+                distances
+                    .iter()
+                    .any(|distance| digits[(index + distance) % used] == **digit)
+            })
+            .map(|(_, digit)| u64::from(digit - b'0'))
+            .sum()),
+    }
+}
+
+// The digit bytes of the input, borrowed when there is nothing to leave out
+fn digit_bytes(input: &[u8], parsing: Parsing) -> Result<Cow<'_, [u8]>, InvalidDigit> {
+    let trimmed = match input.iter().rposition(|byte| !byte.is_ascii_whitespace()) {
+        Some(last) => &input[..=last],
+        None => &input[..0],
+    };
+
+    match trimmed.iter().position(|byte| !byte.is_ascii_digit()) {
+        None => Ok(Cow::Borrowed(trimmed)),
+        Some(position) => match parsing {
+            Parsing::Strict => Err(InvalidDigit {
+                position: position as u64,
+                found: String::from_utf8_lossy(&input[position..])
+                    .chars()
+                    .next()
+                    .unwrap_or('\0'),
+            }),
+            Parsing::Lenient => Ok(Cow::Owned(trimmed.iter().copied().filter(u8::is_ascii_digit).collect())),
+        },
+    }
+}
+
+// Sum the digit values of `a` wherever it has the same byte as `b`. Both must be the same length
+fn sum_equal(a: &[u8], b: &[u8]) -> u64 {
+    debug_assert_eq!(a.len(), b.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            // Safe as the CPU was just checked to support AVX2
+            return unsafe { x86_64::sum_equal_avx2(a, b) };
+        }
+
+        // SSE2 is part of every x86_64 CPU
+        unsafe { x86_64::sum_equal_sse2(a, b) }
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    sum_equal_scalar(a, b)
+}
+
+fn sum_equal_scalar(a: &[u8], b: &[u8]) -> u64 {
+    a.iter()
+        .zip(b.iter())
+        .filter(|(x, y)| x == y)
+        .map(|(x, _)| u64::from(x - b'0'))
+        .sum()
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+    use super::sum_equal_scalar;
+    use std::arch::x86_64::*;
+
+    // Compare 16 bytes at a time, keep the digit values where they are equal and add them up with `psadbw`,
+    // which sums each half of the vector into a 64 bit lane
+    #[target_feature(enable = "sse2")]
+    pub unsafe fn sum_equal_sse2(a: &[u8], b: &[u8]) -> u64 {
+        const WIDTH: usize = 16;
+        let blocks = a.len() / WIDTH;
+        let zero = _mm_setzero_si128();
+        let ascii_zero = _mm_set1_epi8(b'0' as i8);
+        let mut sums = _mm_setzero_si128();
+
+        for block in 0..blocks {
+            let x = _mm_loadu_si128(a.as_ptr().add(block * WIDTH) as *const __m128i);
+            let y = _mm_loadu_si128(b.as_ptr().add(block * WIDTH) as *const __m128i);
+            let matching = _mm_and_si128(_mm_cmpeq_epi8(x, y), _mm_sub_epi8(x, ascii_zero));
+            sums = _mm_add_epi64(sums, _mm_sad_epu8(matching, zero));
+        }
+
+        let mut lanes = [0u64; 2];
+        _mm_storeu_si128(lanes.as_mut_ptr() as *mut __m128i, sums);
+        let rest = blocks * WIDTH;
+
+        lanes.iter().sum::<u64>() + sum_equal_scalar(&a[rest..], &b[rest..])
+    }
+
+    // The same as `sum_equal_sse2` on 32 bytes at a time
+    #[target_feature(enable = "avx2")]
+    pub unsafe fn sum_equal_avx2(a: &[u8], b: &[u8]) -> u64 {
+        const WIDTH: usize = 32;
+        let blocks = a.len() / WIDTH;
+        let zero = _mm256_setzero_si256();
+        let ascii_zero = _mm256_set1_epi8(b'0' as i8);
+        let mut sums = _mm256_setzero_si256();
+
+        for block in 0..blocks {
+            let x = _mm256_loadu_si256(a.as_ptr().add(block * WIDTH) as *const __m256i);
+            let y = _mm256_loadu_si256(b.as_ptr().add(block * WIDTH) as *const __m256i);
+            let matching = _mm256_and_si256(_mm256_cmpeq_epi8(x, y), _mm256_sub_epi8(x, ascii_zero));
+            sums = _mm256_add_epi64(sums, _mm256_sad_epu8(matching, zero));
+        }
+
+        let mut lanes = [0u64; 4];
+        _mm256_storeu_si256(lanes.as_mut_ptr() as *mut __m256i, sums);
+        let rest = blocks * WIDTH;
+
+        lanes.iter().sum::<u64>() + sum_equal_scalar(&a[rest..], &b[rest..])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_01::{captcha_with, parse_input};
+    use crate::testing::Lcg;
+
+    // Pseudo random digits from a small alphabet, so plenty of neighbours match
+    fn random_digits(length: usize, seed: u32) -> Vec<u8> {
+        let mut random = Lcg::new(seed);
+        (0..length).map(|_| b'0' + random.below(4) as u8 * 3).collect()
+    }
+
+    #[test]
+    fn agrees_with_the_scalar_solvers() {
+        let offsets: Vec<Offset> = ["1", "1/2", "2/3", "7", "1,1/2"]
+            .iter()
+            .map(|offset| offset.parse().unwrap())
+            .collect();
+
+        for length in (1..200).chain([1000, 4099, 65_536]) {
+            let input = random_digits(length, length as u32);
+            let digits = parse_input(std::str::from_utf8(&input).unwrap());
+
+            for offset in &offsets {
+                for policy in [LengthPolicy::Reject, LengthPolicy::Skip, LengthPolicy::Round] {
+                    let expected = captcha_with(&digits, offset, policy).ok().map(u64::from);
+                    let actual = byte_captcha(&input, offset, policy, Parsing::Strict).ok();
+                    assert_eq!(actual, expected, "length {}, offset {}, {:?}", length, offset, policy);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn vectorized_kernels_agree_with_the_scalar_kernel() {
+        for length in [0, 15, 16, 17, 31, 32, 33, 100, 1023] {
+            let a = random_digits(length, 1);
+            let b = random_digits(length, 2);
+            assert_eq!(sum_equal(&a, &b), sum_equal_scalar(&a, &b));
+            assert_eq!(sum_equal(&a, &a), sum_equal_scalar(&a, &a));
+        }
+    }
+
+    #[test]
+    fn handles_whitespace_and_other_bytes() {
+        let half = Offset::Fraction(1, 2);
+        assert_eq!(
+            byte_captcha(b"123123\n", &half, LengthPolicy::Reject, Parsing::Strict).unwrap(),
+            12
+        );
+        assert_eq!(
+            byte_captcha(b"12 31x23", &half, LengthPolicy::Reject, Parsing::Lenient).unwrap(),
+            12
+        );
+        assert_eq!(
+            byte_captcha(b"12 3123", &half, LengthPolicy::Reject, Parsing::Strict)
+                .unwrap_err()
+                .to_string(),
+            "Expected a digit at byte 2, found ' '"
+        );
+        assert!(byte_captcha(b"\n", &half, LengthPolicy::Reject, Parsing::Lenient).is_err());
+    }
+}
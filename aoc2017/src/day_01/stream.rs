@@ -0,0 +1,258 @@
+use super::{captcha, Alphabet, DigitFilter, LengthPolicy, Offset, Parsing};
+use anyhow::Context;
+use std::collections::VecDeque;
+use std::io::{Read, Seek, SeekFrom};
+
+// How many bytes are read from the source at a time
+const CHUNK_SIZE: usize = 64 * 1024;
+
+// Solve a captcha with a fixed offset reading digits from any reader. Only the first `offset` digits and the
+// last `offset` digits are kept in memory, so offset 1 runs in constant memory however long the stream is
+pub fn stream_captcha<R: Read>(reader: R, offset: usize, parsing: Parsing) -> anyhow::Result<u64> {
+    // Both grow only as digits arrive, so a huge offset on a short captcha allocates nothing up front
+    let mut head: Vec<u8> = Vec::new();
+    let mut window: VecDeque<u8> = VecDeque::new();
+    let mut length: usize = 0;
+    let mut sum: u64 = 0;
+
+    for_each_digit(reader, parsing, |digit| {
+        length += 1;
+        if head.len() < offset {
+            head.push(digit);
+        }
+
+        window.push_back(digit);
+        if window.len() > offset {
+            // The digit `offset` positions back is compared with the one just read
+            if window.pop_front() == Some(digit) {
+                sum += u64::from(digit);
+            }
+        }
+    })?;
+
+    // With offset 0 every digit is compared with itself and leaves the window right away, so count the digits
+    if length == 0 {
+        return Err(anyhow!("The captcha contains no digits"));
+    }
+
+    if length < offset {
+        // The whole captcha is shorter than the offset and fits in memory, so the offset wraps around it
+        let digits: Vec<u32> = head.iter().map(|&digit| u32::from(digit)).collect();
+        return Ok(u64::from(captcha(&digits, &Offset::Fixed(offset))?));
+    }
+
+    // The last `offset` digits wrap around to the first ones
+    Ok(sum
+        + window
+            .iter()
+            .zip(head.iter())
+            .filter(|(last, first)| last == first)
+            .map(|(&digit, _)| u64::from(digit))
+            .sum::<u64>())
+}
+
+// Solve a captcha with any offset from a seekable source such as a file, in two passes. The first pass counts
+// and validates the digits to resolve the offset, and the second compares digits through one cursor per
+// distinct distance
+pub fn seek_captcha<R: Read + Seek>(
+    mut reader: R,
+    offset: &Offset,
+    policy: LengthPolicy,
+    parsing: Parsing,
+) -> anyhow::Result<u64> {
+    let start = reader.stream_position().context("Failed to seek in the captcha")?;
+
+    let mut length: usize = 0;
+    for_each_digit(&mut reader, parsing, |_| length += 1)?;
+    if length == 0 {
+        return Err(anyhow!("The captcha contains no digits"));
+    }
+
+    let (used, distances) = offset.resolve(length, policy)?;
+    let mut digits = DigitCursor::new(start);
+    let mut ahead = Vec::new();
+    for distance in distances {
+        let mut cursor = DigitCursor::new(start);
+        for _ in 0..distance {
+            cursor.next(&mut reader)?;
+        }
+        ahead.push((cursor, distance));
+    }
+
+    let mut sum: u64 = 0;
+    for _ in 0..used {
+        let digit = digits
+            .next(&mut reader)?
+            .context("The captcha changed while it was read")?;
+        let mut matches = false;
+
+        for (cursor, index) in ahead.iter_mut() {
+            if *index == used {
+                // Wrap around to the start of the used digits
+                *cursor = DigitCursor::new(start);
+                *index = 0;
+            }
+
+            let other = cursor
+                .next(&mut reader)?
+                .context("The captcha changed while it was read")?;
+            *index += 1;
+            matches |= other == digit;
+        }
+
+        if matches {
+            sum += u64::from(digit);
+        }
+    }
+
+    Ok(sum)
+}
+
+// Call the closure with the value of each decimal digit in the source, skipping or rejecting anything else
+fn for_each_digit<R: Read, F: FnMut(u8)>(mut reader: R, parsing: Parsing, mut f: F) -> anyhow::Result<()> {
+    let mut buffer = vec![0; CHUNK_SIZE];
+    let decimal = Alphabet::default();
+    let mut filter = DigitFilter::new(&decimal, parsing);
+    let mut position: u64 = 0;
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(anyhow!(e).context("Failed to read the captcha")),
+        };
+
+        for byte in &buffer[..read] {
+            // Bytes outside ASCII are never digits, and are reported as is without decoding them
+            let c = match byte.is_ascii() {
+                true => char::from(*byte),
+                false => char::REPLACEMENT_CHARACTER,
+            };
+
+            if let Some(digit) = filter.digit(position, c)? {
+                f(digit as u8);
+            }
+            position += 1;
+        }
+    }
+}
+
+// An independent read position in a seekable source, buffering one chunk at a time
+struct DigitCursor {
+    position: u64,
+    buffer: Vec<u8>,
+    index: usize,
+}
+
+impl DigitCursor {
+    fn new(position: u64) -> DigitCursor {
+        DigitCursor {
+            position,
+            buffer: Vec::new(),
+            index: 0,
+        }
+    }
+
+    fn next<R: Read + Seek>(&mut self, reader: &mut R) -> anyhow::Result<Option<u8>> {
+        loop {
+            while let Some(&byte) = self.buffer.get(self.index) {
+                self.index += 1;
+                if byte.is_ascii_digit() {
+                    return Ok(Some(byte - b'0'));
+                }
+            }
+
+            if !self.fill(reader)? {
+                return Ok(None);
+            }
+        }
+    }
+
+    // Read the next chunk at this cursor's position. Returns false at the end of the source
+    fn fill<R: Read + Seek>(&mut self, reader: &mut R) -> anyhow::Result<bool> {
+        reader
+            .seek(SeekFrom::Start(self.position))
+            .context("Failed to seek in the captcha")?;
+
+        self.buffer.resize(CHUNK_SIZE, 0);
+        let read = reader.read(&mut self.buffer).context("Failed to read the captcha")?;
+        self.buffer.truncate(read);
+        self.index = 0;
+        self.position += read as u64;
+
+        Ok(read > 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_01::parse_input;
+    use crate::testing::Lcg;
+    use std::io::Cursor;
+
+    // A long pseudo random captcha spanning several chunks, with a line break after every 1000 digits
+    fn long_captcha() -> String {
+        let mut random = Lcg::new(17);
+        let mut captcha = String::new();
+
+        for index in 1..=3 * CHUNK_SIZE {
+            captcha.push(char::from(b'0' + random.below(3) as u8));
+            if index % 1000 == 0 {
+                captcha.push('\n');
+            }
+        }
+
+        captcha
+    }
+
+    #[test]
+    fn streams_fixed_offsets_like_the_slice_solver() {
+        let input = long_captcha();
+        let digits = parse_input(&input);
+
+        for offset in [1, 2, 7, 1000] {
+            let expected = captcha(&digits, &Offset::Fixed(offset)).unwrap();
+            assert_eq!(
+                stream_captcha(input.as_bytes(), offset, Parsing::Lenient).unwrap(),
+                u64::from(expected)
+            );
+        }
+
+        assert_eq!(stream_captcha("1212".as_bytes(), 6, Parsing::Lenient).unwrap(), 6);
+        assert_eq!(stream_captcha("1234".as_bytes(), 4, Parsing::Lenient).unwrap(), 10);
+        assert_eq!(stream_captcha("1234".as_bytes(), 0, Parsing::Lenient).unwrap(), 10);
+        assert_eq!(
+            stream_captcha("1234".as_bytes(), 9_999_999_999_999, Parsing::Lenient).unwrap(),
+            0
+        );
+        assert!(stream_captcha("\n".as_bytes(), 1, Parsing::Lenient).is_err());
+        assert!(stream_captcha(input.as_bytes(), 1, Parsing::Strict).is_err());
+    }
+
+    #[test]
+    fn seeks_any_offset_like_the_slice_solver() {
+        let input = long_captcha();
+        let digits = parse_input(&input);
+
+        for offset in ["1", "1/2", "1,1/3,2/3", "5"] {
+            let offset: Offset = offset.parse().unwrap();
+            let expected = captcha(&digits, &offset).unwrap();
+            assert_eq!(
+                seek_captcha(Cursor::new(&input), &offset, LengthPolicy::Reject, Parsing::Lenient).unwrap(),
+                u64::from(expected)
+            );
+        }
+
+        let half = Offset::Fraction(1, 2);
+        let strict = |input: &str, policy| seek_captcha(Cursor::new(input), &half, policy, Parsing::Strict);
+        assert_eq!(strict("123123\n", LengthPolicy::Reject).unwrap(), 12);
+        assert!(strict("12131", LengthPolicy::Reject).is_err());
+        assert_eq!(strict("12131", LengthPolicy::Skip).unwrap(), 2);
+        assert_eq!(strict("12131", LengthPolicy::Round).unwrap(), 2);
+
+        let error = strict("1212x", LengthPolicy::Reject).unwrap_err();
+        assert_eq!(error.to_string(), "Expected a digit at byte 4, found 'x'");
+    }
+}
@@ -0,0 +1,352 @@
+use crate::error::{tokens_with_columns, ParseError};
+use crate::solution::Solution;
+use anyhow::Context;
+use cell::Cell;
+use pairs::{divisible_pairs, first_divisible_pair};
+use std::fmt;
+use std::str::FromStr;
+
+pub mod audit;
+pub mod cell;
+pub mod import;
+pub mod pairs;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_spreadsheet(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        spreadsheet_checksum(input, &Spread)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        spreadsheet_checksum_by_division(input)
+    }
+}
+
+// A way to boil a single row of the spreadsheet down to its checksum, for cells of any `Cell` type
+pub trait RowChecksum<T: Cell = u32> {
+    fn row_checksum(&self, row: &[T]) -> anyhow::Result<T>;
+}
+
+/* The difference between the largest and smallest value, as in D2P1 */
+pub struct Spread;
+
+/* The quotient of the only two values that divide evenly, as in D2P2 */
+pub struct EvenDivision;
+
+/* The sum of the quotients of every pair of values that divide evenly */
+pub struct DivisiblePairSum;
+
+/* The median absolute deviation: the median distance of the values from their median */
+pub struct MedianSpread;
+
+/* The greatest common divisor of all values in the row */
+pub struct Gcd;
+
+impl<T: Cell> RowChecksum<T> for Spread {
+    fn row_checksum(&self, row: &[T]) -> anyhow::Result<T> {
+        row_checksum(row)
+    }
+}
+
+impl<T: Cell> RowChecksum<T> for EvenDivision {
+    fn row_checksum(&self, row: &[T]) -> anyhow::Result<T> {
+        row_checksum_division(row)
+    }
+}
+
+impl<T: Cell> RowChecksum<T> for DivisiblePairSum {
+    fn row_checksum(&self, row: &[T]) -> anyhow::Result<T> {
+        divisible_pairs(row).iter().try_fold(T::zero(), |sum, pair| {
+            sum.try_add(&pair.quotient())
+                .ok_or_else(|| anyhow!("The sum of the quotients does not fit in {}", T::WIDTH))
+        })
+    }
+}
+
+impl<T: Cell> RowChecksum<T> for MedianSpread {
+    fn row_checksum(&self, row: &[T]) -> anyhow::Result<T> {
+        let middle = median(row.to_vec()).ok_or_else(|| anyhow!("The row is empty"))?;
+        let deviations: Vec<T> = row
+            .iter()
+            .map(|value| value.max(&middle).difference(value.min(&middle)))
+            .collect();
+
+        Ok(median(deviations).unwrap_or_else(T::zero))
+    }
+}
+
+impl<T: Cell> RowChecksum<T> for Gcd {
+    fn row_checksum(&self, row: &[T]) -> anyhow::Result<T> {
+        if row.is_empty() {
+            return Err(anyhow!("The row is empty"));
+        }
+
+        Ok(row.iter().cloned().fold(T::zero(), gcd))
+    }
+}
+
+// The row checksums that can be picked by name, such as on the command line
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Strategy {
+    Spread,
+    EvenDivision,
+    DivisiblePairSum,
+    MedianSpread,
+    Gcd,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 5] = [
+        Strategy::Spread,
+        Strategy::EvenDivision,
+        Strategy::DivisiblePairSum,
+        Strategy::MedianSpread,
+        Strategy::Gcd,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Strategy::Spread => "spread",
+            Strategy::EvenDivision => "division",
+            Strategy::DivisiblePairSum => "divisible-pairs",
+            Strategy::MedianSpread => "median-spread",
+            Strategy::Gcd => "gcd",
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.name())
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Strategy> {
+        Strategy::ALL
+            .iter()
+            .copied()
+            .find(|strategy| strategy.name() == s)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Strategy::ALL.iter().map(Strategy::name).collect();
+                anyhow!("Expected one of {}, found {:?}", names.join(", "), s)
+            })
+    }
+}
+
+impl<T: Cell> RowChecksum<T> for Strategy {
+    fn row_checksum(&self, row: &[T]) -> anyhow::Result<T> {
+        match self {
+            Strategy::Spread => Spread.row_checksum(row),
+            Strategy::EvenDivision => EvenDivision.row_checksum(row),
+            Strategy::DivisiblePairSum => DivisiblePairSum.row_checksum(row),
+            Strategy::MedianSpread => MedianSpread.row_checksum(row),
+            Strategy::Gcd => Gcd.row_checksum(row),
+        }
+    }
+}
+
+/* Sum the checksums of every row, using any strategy. D2P1 uses `Spread` */
+pub fn spreadsheet_checksum<T: Cell, C: RowChecksum<T> + ?Sized>(
+    spreadsheet: &[Vec<T>],
+    strategy: &C,
+) -> anyhow::Result<T> {
+    spreadsheet
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            strategy
+                .row_checksum(line)
+                .with_context(|| format!("Invalid row {}", index + 1))
+        })
+        .try_fold(T::zero(), |sum, checksum| {
+            sum.try_add(&checksum?)
+                .ok_or_else(|| anyhow!("The checksum does not fit in {}", T::WIDTH))
+        })
+}
+
+/* D2P2 */
+pub fn spreadsheet_checksum_by_division(spreadsheet: &[Vec<u32>]) -> anyhow::Result<u32> {
+    spreadsheet_checksum(spreadsheet, &EvenDivision)
+}
+
+/* Calculate the checksum of a single row using the first algorithm */
+fn row_checksum<T: Cell>(row: &[T]) -> anyhow::Result<T> {
+    let minimum = row.iter().min().ok_or_else(|| anyhow!("The row is empty"))?;
+    let maximum = row.iter().max().ok_or_else(|| anyhow!("The row is empty"))?;
+
+    Ok(maximum.difference(minimum))
+}
+
+/* Calculate the checksum of a single row using the second algorithm */
+fn row_checksum_division<T: Cell>(row: &[T]) -> anyhow::Result<T> {
+    match first_divisible_pair(row) {
+        None => Err(anyhow!("No two values in the row divide evenly")),
+        Some(pair) => Ok(pair.quotient()),
+    }
+}
+
+// The lower middle value, or None if there are no values
+fn median<T: Cell>(mut values: Vec<T>) -> Option<T> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let middle = (values.len() - 1) / 2;
+    Some(values.select_nth_unstable(middle).1.clone())
+}
+
+fn gcd<T: Cell>(a: T, b: T) -> T {
+    match b.is_zero() {
+        true => a,
+        false => {
+            let remainder = a.div_rem(&b).1;
+            gcd(b, remainder)
+        }
+    }
+}
+
+// A row of the spreadsheet with the line of the input it was read from, counted from 1 as in parse and import errors
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberedRow<T = u32> {
+    pub line: usize,
+    // The column each cell was read from, counted from 1. Blank cells that were skipped leave gaps
+    pub columns: Vec<usize>,
+    pub cells: Vec<T>,
+}
+
+/* Parse each non-blank line of the input into a row of numbers */
+pub fn parse_spreadsheet(spreadsheet_input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parse_cells(spreadsheet_input)
+}
+
+/* Parse the spreadsheet into cells of any type, such as `u128` or `BigUint` for numbers too large for `u32` */
+pub fn parse_cells<T: Cell>(spreadsheet_input: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    parse_numbered_cells(spreadsheet_input).map(|rows| rows.into_iter().map(|row| row.cells).collect())
+}
+
+/* Like `parse_cells`, but keeping the line and columns each row was read from */
+pub fn parse_numbered_cells<T: Cell>(spreadsheet_input: &str) -> Result<Vec<NumberedRow<T>>, ParseError> {
+    spreadsheet_input
+        .lines()
+        .enumerate()
+        .filter(|(_, row)| !row.trim().is_empty())
+        .map(|(index, row)| {
+            let cells = parse_row(row, index + 1)?;
+            Ok(NumberedRow {
+                line: index + 1,
+                columns: (1..=cells.len()).collect(),
+                cells,
+            })
+        })
+        .collect::<Result<Vec<NumberedRow<T>>, ParseError>>()
+}
+
+fn parse_row<T: Cell>(row: &str, line: usize) -> Result<Vec<T>, ParseError> {
+    tokens_with_columns(row)
+        .map(|(column, x)| T::parse_cell(x).map_err(|message| ParseError::new(line, column, message)))
+        .collect::<Result<Vec<T>, ParseError>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_checksum_test() {
+        assert_eq!(row_checksum(&parse_row::<u32>("5 1 9 5", 1).unwrap()).unwrap(), 8);
+        assert_eq!(row_checksum(&parse_row::<u32>("7 5 3", 1).unwrap()).unwrap(), 4);
+        assert_eq!(row_checksum(&parse_row::<u32>("2 4 6 8", 1).unwrap()).unwrap(), 6);
+    }
+
+    #[test]
+    fn row_checksum_division_test() {
+        assert_eq!(
+            row_checksum_division(&parse_row::<u32>("5 9 2 8", 1).unwrap()).unwrap(),
+            4
+        );
+        assert_eq!(
+            row_checksum_division(&parse_row::<u32>("9 4 7 3", 1).unwrap()).unwrap(),
+            3
+        );
+        assert_eq!(
+            row_checksum_division(&parse_row::<u32>("3 8 6 5", 1).unwrap()).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn reports_invalid_cells_by_line_and_column() {
+        let error = parse_spreadsheet("5 1 9 5\n7 5x 3").unwrap_err();
+        assert_eq!(error, ParseError::new(2, 3, "Expected a number, found \"5x\""));
+    }
+
+    #[test]
+    fn rejects_rows_without_a_checksum() {
+        assert!(row_checksum::<u32>(&[]).is_err());
+        assert!(row_checksum_division(&parse_row::<u32>("5 7 11 3", 1).unwrap()).is_err());
+    }
+
+    #[test]
+    fn sums_rows_with_any_strategy() {
+        let spreadsheet = parse_spreadsheet("5 9 2 8\n9 4 7 3\n3 8 6 5\n12 6 3 0").unwrap();
+
+        // Zero is never used as a divisor, so the last row has the pairs 12/6, 12/3 and 6/3
+        assert_eq!(spreadsheet_checksum(&spreadsheet, &Spread).unwrap(), 7 + 6 + 5 + 12);
+        assert_eq!(
+            spreadsheet_checksum(&spreadsheet, &DivisiblePairSum).unwrap(),
+            4 + 3 + 2 + (2 + 4 + 2)
+        );
+        assert_eq!(
+            spreadsheet_checksum(&spreadsheet, &MedianSpread).unwrap(),
+            3 + 1 + 1 + 3
+        );
+        assert_eq!(spreadsheet_checksum(&spreadsheet, &Gcd).unwrap(), 1 + 1 + 1 + 3);
+        assert!(spreadsheet_checksum(&spreadsheet, &EvenDivision).is_ok());
+    }
+
+    #[test]
+    fn reports_checksums_that_do_not_fit_the_cells() {
+        let input = "4294967295 0\n1 0";
+        let narrow: Vec<Vec<u32>> = parse_cells(input).unwrap();
+        assert_eq!(
+            spreadsheet_checksum(&narrow, &Spread).unwrap_err().to_string(),
+            "The checksum does not fit in 32 bits"
+        );
+
+        let wide: Vec<Vec<u64>> = parse_cells(input).unwrap();
+        assert_eq!(spreadsheet_checksum(&wide, &Spread).unwrap(), 1 << 32);
+
+        let big: Vec<Vec<cell::BigUint>> = parse_cells("340282366920938463463374607431768211455 0\n1 0").unwrap();
+        assert_eq!(
+            spreadsheet_checksum(&big, &Strategy::Spread).unwrap().to_string(),
+            "340282366920938463463374607431768211456"
+        );
+    }
+
+    #[test]
+    fn picks_strategies_by_name() {
+        for strategy in Strategy::ALL.iter() {
+            assert_eq!(strategy.name().parse::<Strategy>().unwrap(), *strategy);
+        }
+
+        assert_eq!(
+            "average".parse::<Strategy>().unwrap_err().to_string(),
+            "Expected one of spread, division, divisible-pairs, median-spread, gcd, found \"average\""
+        );
+        assert_eq!(Strategy::Gcd.row_checksum(&[12u32, 18, 30]).unwrap(), 6);
+    }
+}
@@ -0,0 +1,175 @@
+use super::cell::{self, Cell};
+use super::pairs::first_divisible_pair;
+use super::{EvenDivision, NumberedRow, RowChecksum};
+use anyhow::Context;
+use serde::Serialize;
+use std::fmt;
+
+// How each row of a spreadsheet adds up to its checksum. Displays as a table, and serializes to JSON
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(bound(serialize = "T: Cell"))]
+pub struct Audit<T = u32> {
+    pub rows: Vec<RowAudit<T>>,
+    #[serde(serialize_with = "cell::serialize")]
+    pub checksum: T,
+}
+
+// A row's smallest and largest values, the first pair that divides evenly, and what the row added to the checksum
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(bound(serialize = "T: Cell"))]
+pub struct RowAudit<T = u32> {
+    // The line of the input the row was read from, counted from 1 as in parse and import errors
+    pub row: usize,
+    #[serde(serialize_with = "cell::serialize_option")]
+    pub min: Option<T>,
+    #[serde(serialize_with = "cell::serialize_option")]
+    pub max: Option<T>,
+    #[serde(serialize_with = "cell::serialize_option")]
+    pub dividend: Option<T>,
+    #[serde(serialize_with = "cell::serialize_option")]
+    pub divisor: Option<T>,
+    #[serde(serialize_with = "cell::serialize")]
+    pub contribution: T,
+}
+
+/* Like `spreadsheet_checksum`, but keeping the breakdown of every row. The rows carry the line they were read from,
+as returned by `parse_numbered_cells` or `import_cells` */
+pub fn spreadsheet_audit<T: Cell, C: RowChecksum<T> + ?Sized>(
+    spreadsheet: &[NumberedRow<T>],
+    strategy: &C,
+) -> anyhow::Result<Audit<T>> {
+    let mut rows = Vec::with_capacity(spreadsheet.len());
+    let mut checksum = T::zero();
+
+    for &NumberedRow {
+        line, cells: ref row, ..
+    } in spreadsheet
+    {
+        let contribution = strategy
+            .row_checksum(row)
+            .with_context(|| format!("Invalid row {}", line))?;
+        checksum = checksum
+            .try_add(&contribution)
+            .ok_or_else(|| anyhow!("The checksum does not fit in {} at row {}", T::WIDTH, line))?;
+
+        let pair = first_divisible_pair(row);
+        rows.push(RowAudit {
+            row: line,
+            min: row.iter().min().cloned(),
+            max: row.iter().max().cloned(),
+            dividend: pair.as_ref().map(|pair| pair.dividend.clone()),
+            divisor: pair.map(|pair| pair.divisor),
+            contribution,
+        });
+    }
+
+    Ok(Audit { rows, checksum })
+}
+
+/* Like `spreadsheet_checksum_by_division`, but keeping the breakdown of every row */
+pub fn spreadsheet_audit_by_division(spreadsheet: &[NumberedRow]) -> anyhow::Result<Audit> {
+    spreadsheet_audit(spreadsheet, &EvenDivision)
+}
+
+impl<T: Cell> fmt::Display for Audit<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cell = |value: &Option<T>| value.as_ref().map_or_else(|| String::from("-"), T::to_string);
+        let headings = ["Row", "Min", "Max", "Dividend", "Divisor", "Checksum"];
+        let lines: Vec<[String; 6]> = self
+            .rows
+            .iter()
+            .map(|row| {
+                [
+                    row.row.to_string(),
+                    cell(&row.min),
+                    cell(&row.max),
+                    cell(&row.dividend),
+                    cell(&row.divisor),
+                    row.contribution.to_string(),
+                ]
+            })
+            .collect();
+
+        let mut widths = headings.map(str::len);
+        for line in &lines {
+            for (width, text) in widths.iter_mut().zip(line.iter()) {
+                *width = (*width).max(text.len());
+            }
+        }
+        widths[5] = widths[5].max(self.checksum.to_string().len());
+
+        let headings: Vec<String> = headings
+            .iter()
+            .zip(widths.iter())
+            .map(|(heading, width)| format!("{:>width$}", heading, width = width))
+            .collect();
+        writeln!(f, "{}", headings.join(" | "))?;
+        let rules: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        writeln!(f, "{}", rules.join("-+-"))?;
+
+        for line in &lines {
+            let cells: Vec<String> = line
+                .iter()
+                .zip(widths.iter())
+                .map(|(text, width)| format!("{:>width$}", text, width = width))
+                .collect();
+            writeln!(f, "{}", cells.join(" | "))?;
+        }
+
+        let label_width: usize = widths[..5].iter().sum::<usize>() + 4 * " | ".len();
+        writeln!(
+            f,
+            "{:>label_width$} | {:>width$}",
+            "Total",
+            self.checksum,
+            label_width = label_width,
+            width = widths[5]
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_02::{parse_numbered_cells, Spread};
+
+    #[test]
+    fn explains_each_row() {
+        let spreadsheet = parse_numbered_cells("5 9 2 8\n9 4 7 3\n3 8 6 5").unwrap();
+        let audit = spreadsheet_audit_by_division(&spreadsheet).unwrap();
+
+        assert_eq!(audit.checksum, 9);
+        assert_eq!(
+            audit.rows[1],
+            RowAudit {
+                row: 2,
+                min: Some(3),
+                max: Some(9),
+                dividend: Some(9),
+                divisor: Some(3),
+                contribution: 3,
+            }
+        );
+
+        let json = serde_json::to_value(&audit).unwrap();
+        assert_eq!(json["rows"][0]["dividend"], 8);
+        assert_eq!(json["checksum"], 9);
+    }
+
+    #[test]
+    fn renders_a_table() {
+        let spreadsheet = parse_numbered_cells::<u32>("5 1 9 5\n\n7 5 3").unwrap();
+        let audit = spreadsheet_audit(&spreadsheet, &Spread).unwrap();
+
+        assert_eq!(
+            audit.to_string(),
+            concat!(
+                "Row | Min | Max | Dividend | Divisor | Checksum\n",
+                "----+-----+-----+----------+---------+---------\n",
+                "  1 |   1 |   9 |        5 |       1 |        8\n",
+                "  3 |   3 |   7 |        - |       - |        4\n",
+                "                               Total |       12\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use num_traits::{CheckedAdd, Num, ToPrimitive};
+use serde::Serializer;
+use std::fmt;
+use std::num::IntErrorKind;
+
+pub use num_bigint::BigUint;
+
+// A non-negative number a spreadsheet cell can hold. Checksums never wrap around: sums that do not fit are reported
+// as errors, as are cells too large for the type
+pub trait Cell: Clone + Ord + fmt::Debug + fmt::Display + Num + CheckedAdd + ToPrimitive {
+    // How large the values may get, for error messages
+    const WIDTH: &'static str;
+
+    fn parse_cell(text: &str) -> Result<Self, String>;
+
+    // Write the cell to JSON. Cells of up to 64 bits are numbers, and wider ones are strings of digits, since
+    // many JSON readers cannot hold larger numbers exactly
+    fn serialize_cell<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+
+    fn try_add(&self, other: &Self) -> Option<Self> {
+        CheckedAdd::checked_add(self, other)
+    }
+
+    // The difference to a value no larger than this one
+    fn difference(&self, smaller: &Self) -> Self {
+        self.clone() - smaller.clone()
+    }
+
+    // The quotient and remainder of dividing by a value other than zero
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        (self.clone() / divisor.clone(), self.clone() % divisor.clone())
+    }
+
+    fn is_divisible_by(&self, divisor: &Self) -> bool {
+        !divisor.is_zero() && self.div_rem(divisor).1.is_zero()
+    }
+}
+
+macro_rules! primitive_cell {
+    ($($type:ty, $width:expr, $serialize:ident;)*) => {$(
+        impl Cell for $type {
+            const WIDTH: &'static str = $width;
+
+            fn parse_cell(text: &str) -> Result<$type, String> {
+                text.parse().map_err(|error: std::num::ParseIntError| match error.kind() {
+                    IntErrorKind::PosOverflow => format!("{} does not fit in {}", text, Self::WIDTH),
+                    _ => format!("Expected a number, found {:?}", text),
+                })
+            }
+
+            fn serialize_cell<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $serialize(self, serializer)
+            }
+        }
+    )*};
+}
+
+primitive_cell! {
+    u32, "32 bits", as_number;
+    u64, "64 bits", as_number;
+    u128, "128 bits", as_digits;
+}
+
+impl Cell for BigUint {
+    const WIDTH: &'static str = "any size";
+
+    fn parse_cell(text: &str) -> Result<BigUint, String> {
+        // Only plain digits, as for the primitive cells, rather than the underscores `BigUint` also accepts
+        let digits = text.strip_prefix('+').unwrap_or(text);
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(format!("Expected a number, found {:?}", text));
+        }
+
+        digits
+            .parse()
+            .map_err(|_| format!("Expected a number, found {:?}", text))
+    }
+
+    fn serialize_cell<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        as_digits(self, serializer)
+    }
+}
+
+fn as_number<T: ToPrimitive, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(value.to_u64().expect("The cell fits in 64 bits"))
+}
+
+fn as_digits<T: fmt::Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+// For `#[serde(serialize_with)]` on cell fields
+pub fn serialize<T: Cell, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.serialize_cell(serializer)
+}
+
+// For `#[serde(serialize_with)]` on optional cell fields
+pub fn serialize_option<T: Cell, S: Serializer>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error> {
+    match value {
+        Some(value) => value.serialize_cell(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_cells_that_do_not_fit() {
+        assert_eq!(u32::parse_cell("4294967295"), Ok(u32::MAX));
+        assert_eq!(
+            u32::parse_cell("4294967296").unwrap_err(),
+            "4294967296 does not fit in 32 bits"
+        );
+        assert_eq!(u64::parse_cell("x1").unwrap_err(), "Expected a number, found \"x1\"");
+        assert_eq!(u128::parse_cell("18446744073709551616"), Ok(1 << 64));
+        assert!(BigUint::parse_cell("1-2").is_err());
+        assert!(BigUint::parse_cell("1_000").is_err());
+        assert_eq!(BigUint::parse_cell("+12"), Ok(BigUint::from(12u32)));
+    }
+
+    #[test]
+    fn big_integers_agree_with_u128() {
+        let values: Vec<u128> = vec![0, 1, 7, (1 << 32) - 1, 10_u128.pow(20) + 3, u128::MAX];
+
+        for a in &values {
+            let big_a = BigUint::parse_cell(&a.to_string()).unwrap();
+            for b in &values {
+                let big_b = BigUint::from(*b);
+                if let Some(sum) = u128::checked_add(*a, *b) {
+                    assert_eq!(big_a.try_add(&big_b), Some(BigUint::from(sum)));
+                }
+                if a >= b {
+                    assert_eq!(big_a.difference(&big_b), BigUint::from(a - b));
+                }
+                if *b != 0 {
+                    assert_eq!(big_a.is_divisible_by(&big_b), a % b == 0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn writes_wide_cells_to_json_as_digits() {
+        let json = |value: serde_json::Result<serde_json::Value>| value.unwrap().to_string();
+
+        assert_eq!(
+            json(serialize(&u64::MAX, serde_json::value::Serializer)),
+            "18446744073709551615"
+        );
+        assert_eq!(json(serialize(&1u128, serde_json::value::Serializer)), "\"1\"");
+        assert_eq!(
+            json(serialize(&BigUint::from(1u32), serde_json::value::Serializer)),
+            "\"1\""
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use super::cell::Cell;
+use super::NumberedRow;
+use std::fmt;
+use std::str::FromStr;
+
+// How to read a delimited spreadsheet export such as a CSV or TSV file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportOptions {
+    pub separator: char,
+    // Whether the first row names the columns rather than holding numbers
+    pub header: bool,
+    pub blank_cells: BlankCells,
+}
+
+impl ImportOptions {
+    pub fn csv() -> ImportOptions {
+        ImportOptions {
+            separator: ',',
+            header: false,
+            blank_cells: BlankCells::default(),
+        }
+    }
+
+    pub fn tsv() -> ImportOptions {
+        ImportOptions {
+            separator: '\t',
+            ..ImportOptions::csv()
+        }
+    }
+}
+
+// What to do with cells that are empty or only hold whitespace
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BlankCells {
+    // Leave them out of the row
+    #[default]
+    Skip,
+    // Count them as 0
+    Zero,
+    // Report them as errors
+    Reject,
+}
+
+impl FromStr for BlankCells {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<BlankCells> {
+        match s {
+            "skip" => Ok(BlankCells::Skip),
+            "zero" => Ok(BlankCells::Zero),
+            "reject" => Ok(BlankCells::Reject),
+            _ => Err(anyhow!("Expected skip, zero or reject, found {:?}", s)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Import<T = u32> {
+    pub header: Option<Vec<String>>,
+    // Each row with the line and columns it was read from, counted as in `CellError`
+    pub rows: Vec<NumberedRow<T>>,
+}
+
+// A cell that could not be imported. Lines and columns start at 1 and count the header row and blank lines. The line
+// is where the cell starts, since a quoted cell may span several lines
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CellError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+// Every cell that could not be imported, in the order they appear
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImportError {
+    pub errors: Vec<CellError>,
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let errors: Vec<String> = self.errors.iter().map(CellError::to_string).collect();
+        write!(f, "{}", errors.join("\n"))
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+// Import a delimited spreadsheet. Fields may be quoted with `"`, with `""` standing for a quote inside them and
+// whitespace allowed around the quotes, and blank lines are skipped
+pub fn import(input: &str, options: &ImportOptions) -> Result<Import, ImportError> {
+    import_cells(input, options)
+}
+
+// Import a delimited spreadsheet into cells of any type, such as `u128` or `BigUint` for numbers too large for `u32`
+pub fn import_cells<T: Cell>(input: &str, options: &ImportOptions) -> Result<Import<T>, ImportError> {
+    let records = split_records(input, options.separator).map_err(|error| ImportError { errors: vec![error] })?;
+    let mut records = records.into_iter();
+
+    let header = match options.header {
+        true => records
+            .next()
+            .map(|fields| fields.into_iter().map(|field| field.text.trim().to_owned()).collect()),
+        false => None,
+    };
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for fields in records {
+        if is_blank_line(&fields) {
+            continue;
+        }
+
+        let mut row = Vec::with_capacity(fields.len());
+        let mut columns = Vec::with_capacity(fields.len());
+        for (column, field) in fields.iter().enumerate() {
+            let text = field.text.trim();
+            let cell = match (text, options.blank_cells) {
+                ("", BlankCells::Skip) => continue,
+                ("", BlankCells::Zero) => Ok(T::zero()),
+                ("", BlankCells::Reject) => Err(String::from("The cell is blank")),
+                (text, _) => T::parse_cell(text),
+            };
+
+            match cell {
+                Ok(value) => {
+                    row.push(value);
+                    columns.push(column + 1);
+                }
+                Err(message) => errors.push(CellError {
+                    line: field.line,
+                    column: column + 1,
+                    message,
+                }),
+            }
+        }
+        rows.push(NumberedRow {
+            line: fields[0].line,
+            columns,
+            cells: row,
+        });
+    }
+
+    match errors.is_empty() {
+        true => Ok(Import { header, rows }),
+        false => Err(ImportError { errors }),
+    }
+}
+
+struct Field {
+    text: String,
+    quoted: bool,
+    // The line the field starts on
+    line: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum State {
+    FieldStart,
+    Unquoted,
+    Quoted,
+    // Just after a quote inside a quoted field, which either ends the field or escapes another quote
+    AfterQuote,
+}
+
+// Split the input into records of fields, keeping line breaks and separators inside quoted fields
+fn split_records(input: &str, separator: char) -> Result<Vec<Vec<Field>>, CellError> {
+    let mut records = Vec::new();
+    let mut fields: Vec<Field> = Vec::new();
+    let mut text = String::new();
+    let mut state = State::FieldStart;
+    let mut line = 1;
+    let mut field_line = 1;
+
+    for c in input.chars() {
+        match (state, c) {
+            (State::Quoted, '"') => state = State::AfterQuote,
+            (State::Quoted, c) => text.push(c),
+            (State::AfterQuote, '"') => {
+                text.push('"');
+                state = State::Quoted;
+            }
+            (State::FieldStart, '"') => state = State::Quoted,
+            (_, '\n') => {
+                end_field(&mut text, &mut fields, state, field_line);
+                records.push(std::mem::take(&mut fields));
+                state = State::FieldStart;
+                field_line = line + 1;
+            }
+            (_, c) if c == separator => {
+                end_field(&mut text, &mut fields, state, field_line);
+                state = State::FieldStart;
+                field_line = line;
+            }
+            (State::FieldStart, c) | (State::AfterQuote, c) if c.is_whitespace() => (),
+            (State::AfterQuote, c) => {
+                return Err(CellError {
+                    line,
+                    column: fields.len() + 1,
+                    message: format!("Expected a separator after the closing quote, found {:?}", c),
+                })
+            }
+            (State::FieldStart, c) | (State::Unquoted, c) => {
+                text.push(c);
+                state = State::Unquoted;
+            }
+        }
+
+        if c == '\n' {
+            line += 1;
+        }
+    }
+
+    match state {
+        State::Quoted => Err(CellError {
+            line: field_line,
+            column: fields.len() + 1,
+            message: String::from("The quoted cell is never closed"),
+        }),
+        State::FieldStart if fields.is_empty() => Ok(records),
+        state => {
+            end_field(&mut text, &mut fields, state, field_line);
+            records.push(fields);
+            Ok(records)
+        }
+    }
+}
+
+fn end_field(text: &mut String, fields: &mut Vec<Field>, state: State, line: usize) {
+    fields.push(Field {
+        text: std::mem::take(text),
+        quoted: state == State::AfterQuote,
+        line,
+    })
+}
+
+fn is_blank_line(fields: &[Field]) -> bool {
+    match fields {
+        [field] => !field.quoted && field.text.trim().is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imports_csv_with_a_header_and_quotes() {
+        let input = "min,\"max, really\",note\r\n1, 5 ,\"3\"\n\n\"10\",7,\"\n2\"\n";
+        let options = ImportOptions {
+            header: true,
+            ..ImportOptions::csv()
+        };
+
+        let import = import(input, &options).unwrap();
+        assert_eq!(
+            import.header,
+            Some(vec![
+                String::from("min"),
+                String::from("max, really"),
+                String::from("note")
+            ])
+        );
+        assert_eq!(
+            import.rows,
+            vec![
+                NumberedRow {
+                    line: 2,
+                    columns: vec![1, 2, 3],
+                    cells: vec![1, 5, 3]
+                },
+                NumberedRow {
+                    line: 4,
+                    columns: vec![1, 2, 3],
+                    cells: vec![10, 7, 2]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn unescapes_doubled_quotes() {
+        let error = import("1,\"2\"\"\"", &ImportOptions::csv()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 2: Expected a number, found \"2\\\"\""
+        );
+    }
+
+    #[test]
+    fn applies_the_blank_cell_policy() {
+        let input = "5\t\t9\n7\t 5\t\n";
+        let with = |blank_cells| {
+            let options = ImportOptions {
+                blank_cells,
+                ..ImportOptions::tsv()
+            };
+            import(input, &options)
+        };
+        let cells = |import: Import| -> Vec<Vec<u32>> { import.rows.into_iter().map(|row| row.cells).collect() };
+
+        let skipped = with(BlankCells::Skip).unwrap();
+        assert_eq!(skipped.rows[0].columns, vec![1, 3]);
+        assert_eq!(cells(skipped), vec![vec![5, 9], vec![7, 5]]);
+        assert_eq!(
+            cells(with(BlankCells::Zero).unwrap()),
+            vec![vec![5, 0, 9], vec![7, 5, 0]]
+        );
+
+        let errors = with(BlankCells::Reject).unwrap_err().errors;
+        let positions: Vec<(usize, usize)> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(positions, vec![(1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn reports_every_bad_cell() {
+        let error = import("1,x\n\"2\"3,4\n", &ImportOptions::csv()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: Expected a separator after the closing quote, found '3'"
+        );
+
+        let error = import("1,x\n-2,4\n\"5", &ImportOptions::csv()).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: The quoted cell is never closed");
+
+        let error = import("1,x\n-2,4\n", &ImportOptions::csv()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 2: Expected a number, found \"x\"\nline 2, column 1: Expected a number, found \"-2\""
+        );
+    }
+
+    #[test]
+    fn allows_whitespace_around_quotes() {
+        let rows = import("1, \"3\" ,\t\"5\"\n", &ImportOptions::csv()).unwrap().rows;
+        assert_eq!(rows[0].cells, vec![1, 3, 5]);
+
+        let error = import("1, \"3\" x\n", &ImportOptions::csv()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 2: Expected a separator after the closing quote, found 'x'"
+        );
+    }
+
+    #[test]
+    fn counts_lines_inside_quoted_cells() {
+        let input = "1,\"2\n\n\"\n3,x\n\"4\n\",\"\ny\"\n";
+        let error = import(input, &ImportOptions::csv()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 2: Expected a number, found \"x\"\nline 6, column 2: Expected a number, found \"y\""
+        );
+
+        let error = import("1,\"2\n\"\n5,\"6", &ImportOptions::csv()).unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 2: The quoted cell is never closed");
+
+        let rows = import("1,\"2\n\"\n3,4", &ImportOptions::csv()).unwrap().rows;
+        let lines: Vec<usize> = rows.iter().map(|row| row.line).collect();
+        assert_eq!(lines, vec![1, 3]);
+    }
+}
@@ -0,0 +1,181 @@
+use super::cell::Cell;
+use std::ops::Range;
+
+// Two cells of a row where one divides the other evenly. Each pair of cells is reported once: when both hold the
+// same value the earlier one is the dividend, and a 0 is only ever the dividend
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DivisiblePair<T = u32> {
+    pub dividend_index: usize,
+    pub dividend: T,
+    pub divisor_index: usize,
+    pub divisor: T,
+}
+
+impl<T: Cell> DivisiblePair<T> {
+    pub fn quotient(&self) -> T {
+        self.dividend.div_rem(&self.divisor).0
+    }
+}
+
+// Find every pair of cells in the row that divide evenly, ordered by the position of the dividend and then the
+// divisor. The row is sorted once, and each distinct divisor then either steps through its multiples up to the
+// largest value, or tests the larger values directly, whichever is fewer. That is O(n log n) plus the pairs found
+// for the rows the puzzle has, rather than comparing every cell with every other
+pub fn divisible_pairs<T: Cell>(row: &[T]) -> Vec<DivisiblePair<T>> {
+    let mut pairs = Vec::new();
+    for_each_divisible_group(row, |dividends, divisors| {
+        for &dividend_index in dividends {
+            for &divisor_index in divisors {
+                pairs.push(pair_at(row, dividend_index, divisor_index));
+            }
+        }
+    });
+
+    pairs.sort_by_key(|pair| (pair.dividend_index, pair.divisor_index));
+    pairs
+}
+
+// The first pair `divisible_pairs` would report, without collecting the others. A row of n equal cells holds n²/2
+// pairs, but only the earliest cell of each group can be part of the first one, so this needs O(n) memory
+pub fn first_divisible_pair<T: Cell>(row: &[T]) -> Option<DivisiblePair<T>> {
+    let mut first: Option<(usize, usize)> = None;
+    for_each_divisible_group(row, |dividends, divisors| {
+        // Both groups are in row order, so their first cells make the earliest pair between them
+        if let (Some(&dividend_index), Some(&divisor_index)) = (dividends.first(), divisors.first()) {
+            if first.is_none_or(|first| (dividend_index, divisor_index) < first) {
+                first = Some((dividend_index, divisor_index));
+            }
+        }
+    });
+
+    first.map(|(dividend_index, divisor_index)| pair_at(row, dividend_index, divisor_index))
+}
+
+fn pair_at<T: Cell>(row: &[T], dividend_index: usize, divisor_index: usize) -> DivisiblePair<T> {
+    DivisiblePair {
+        dividend_index,
+        dividend: row[dividend_index].clone(),
+        divisor_index,
+        divisor: row[divisor_index].clone(),
+    }
+}
+
+// Call `visit` with groups of cell positions where every cell of the first group is divisible by every cell of the
+// second, covering each pair `divisible_pairs` reports exactly once. The two groups never share a cell, and each
+// lists its positions in row order
+fn for_each_divisible_group<T: Cell, F: FnMut(&[usize], &[usize])>(row: &[T], mut visit: F) {
+    let mut order: Vec<usize> = (0..row.len()).collect();
+    order.sort_by(|&a, &b| row[a].cmp(&row[b]).then(a.cmp(&b)));
+
+    // The distinct values in ascending order, with the range of `order` holding their positions
+    let mut groups: Vec<(&T, Range<usize>)> = Vec::new();
+    for (rank, &index) in order.iter().enumerate() {
+        match groups.last_mut() {
+            Some((value, positions)) if **value == row[index] => positions.end = rank + 1,
+            _ => groups.push((&row[index], rank..rank + 1)),
+        }
+    }
+
+    let largest = match groups.last() {
+        Some((value, _)) => (*value).clone(),
+        None => return,
+    };
+    for (rank, (divisor, positions)) in groups.iter().enumerate() {
+        let divisors = &order[positions.clone()];
+
+        if divisor.is_zero() {
+            // 0 is a multiple of everything but itself
+            for (_, dividends) in &groups[rank + 1..] {
+                visit(divisors, &order[dividends.clone()]);
+            }
+            continue;
+        }
+
+        // Equal values divide each other, and only the earlier one is taken as the dividend
+        for (position, dividend_index) in divisors.iter().enumerate() {
+            visit(std::slice::from_ref(dividend_index), &divisors[position + 1..]);
+        }
+
+        let larger = &groups[rank + 1..];
+        let multiples = largest.div_rem(divisor).0.to_usize();
+        if multiples.is_some_and(|multiples| multiples < larger.len()) {
+            let mut multiple = (*divisor).clone();
+            while let Some(next) = multiple.try_add(divisor).filter(|next| *next <= largest) {
+                if let Ok(found) = larger.binary_search_by(|(value, _)| (*value).cmp(&next)) {
+                    visit(&order[larger[found].1.clone()], divisors);
+                }
+                multiple = next;
+            }
+        } else {
+            for (value, dividends) in larger {
+                if value.is_divisible_by(divisor) {
+                    visit(&order[dividends.clone()], divisors);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Lcg;
+
+    // Compare every cell with every other, keeping the pairs `divisible_pairs` promises to report
+    fn every_pair(row: &[u32]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (i, a) in row.iter().enumerate() {
+            for (j, b) in row.iter().enumerate() {
+                let reported = i != j && *b != 0 && a.is_multiple_of(*b) && (a != b || i < j);
+                if reported {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    #[test]
+    fn finds_the_same_pairs_as_comparing_every_cell() {
+        let mut random = Lcg::new(3);
+        for length in 0..60 {
+            for range in [4, 50, 1000] {
+                let row: Vec<u32> = (0..length).map(|_| random.below(range)).collect();
+
+                let found: Vec<(usize, usize)> = divisible_pairs(&row)
+                    .iter()
+                    .map(|pair| (pair.dividend_index, pair.divisor_index))
+                    .collect();
+                assert_eq!(found, every_pair(&row), "{:?}", row);
+
+                let first = first_divisible_pair(&row).map(|pair| (pair.dividend_index, pair.divisor_index));
+                assert_eq!(first, found.first().copied(), "{:?}", row);
+            }
+        }
+    }
+
+    #[test]
+    fn handles_wide_rows() {
+        // Odd values never divide each other except by 1, so the only pairs are the 2 and 1 at the end
+        let mut row: Vec<u32> = (0..50_000).map(|value| 1_000_001 + 2 * value).collect();
+        row.extend([2, 1]);
+
+        let pairs = divisible_pairs(&row);
+        assert_eq!(pairs.len(), 50_001);
+        assert_eq!(pairs[0].quotient(), 1_000_001);
+        assert_eq!(pairs.last().unwrap().quotient(), 2);
+    }
+
+    #[test]
+    fn finds_the_first_pair_of_a_row_of_equal_cells() {
+        let row = vec![7u32; 100_000];
+        let pair = first_divisible_pair(&row).unwrap();
+
+        assert_eq!((pair.dividend_index, pair.divisor_index), (0, 1));
+        assert_eq!(
+            first_divisible_pair(&[5u32, 3, 0]).map(|pair| pair.dividend_index),
+            Some(2)
+        );
+        assert_eq!(first_divisible_pair(&[5u32, 3]), None);
+    }
+}
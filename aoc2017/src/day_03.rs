@@ -0,0 +1,365 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+use lattice::{Lattice, Square};
+use memory::{GridMemory, Memory};
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+pub mod lattice;
+pub mod memory;
+pub mod render;
+pub mod rule;
+
+pub type Point = (i32, i32);
+pub type ValueFn<L = Square> = dyn Fn(&u32, &<L as Lattice>::Point, &dyn Memory<L>) -> u32;
+
+// Which way the spiral is going on the square lattice
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = u32;
+    type Answer1 = i32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        find_memory_location_with_puzzle_input(*input)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        find_first_value_exceeding_puzzle_input(*input).map(|(_, value)| value)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<u32, ParseError> {
+    let number = input.trim();
+    let offset = input.len() - input.trim_start().len();
+
+    number
+        .parse()
+        .map_err(|_| ParseError::at_offset(input, offset, format!("Expected a square number, found {:?}", number)))
+}
+
+pub fn find_memory_location_with_puzzle_input(puzzle_input: u32) -> anyhow::Result<i32> {
+    if puzzle_input == 0 {
+        return Err(anyhow!("The squares are numbered from 1"));
+    }
+
+    let point = square_to_point(puzzle_input)?;
+    Ok(point.0.abs() + point.1.abs())
+}
+
+// Find where a square sits in the spiral without walking it
+pub fn square_to_point(square: u32) -> anyhow::Result<Point> {
+    if square == 0 {
+        return Err(anyhow!("The squares are numbered from 1"));
+    }
+
+    // Every square a u32 can number lies within 2^16 steps of the access port
+    Ok(spiral_point(u64::from(square)))
+}
+
+// Find the number of the square at a point in the spiral, the inverse of `square_to_point`
+pub fn point_to_square(point: Point) -> anyhow::Result<u32> {
+    let (x, y) = point;
+    spiral_square(point)
+        .and_then(|square| u32::try_from(square).ok())
+        .ok_or_else(|| anyhow!("The square at ({}, {}) is numbered beyond {}", x, y, u32::MAX))
+}
+
+// The point of any square from 1 up to 2^62. Ring k around the access port holds the squares after (2k - 1)² up to
+// (2k + 1)², which ends in its bottom right corner, so the square's side of the ring and its distance from that
+// corner give the point
+fn spiral_point(square: u64) -> Point {
+    let ring = ((square - 1).isqrt() as i64 + 1) / 2;
+    let last = (2 * ring + 1).pow(2);
+    let side = 2 * ring;
+
+    let (x, y) = match last - square as i64 {
+        behind if behind <= side => (ring - behind, -ring),
+        behind if behind <= 2 * side => (-ring, -ring + behind - side),
+        behind if behind <= 3 * side => (-ring + behind - 2 * side, ring),
+        behind => (ring, ring - behind + 3 * side),
+    };
+
+    (x as i32, y as i32)
+}
+
+// The number of the square at any point, or None for the outermost rings of i32 points, numbered beyond 2^62
+fn spiral_square((x, y): Point) -> Option<u64> {
+    let (x, y) = (i64::from(x), i64::from(y));
+    let ring = x.abs().max(y.abs());
+    if ring >= 1 << 30 {
+        return None;
+    }
+    let last = (2 * ring + 1).pow(2);
+    let side = 2 * ring;
+
+    let square = match (x, y) {
+        (x, y) if y == -ring => last - (ring - x),
+        (x, y) if x == -ring => last - side - (y + ring),
+        (x, y) if y == ring => last - 2 * side - (x + ring),
+        (_, y) => last - 3 * side - (ring - y),
+    };
+    Some(square as u64)
+}
+
+pub fn find_first_value_exceeding_puzzle_input(puzzle_input: u32) -> anyhow::Result<(Point, u32)> {
+    first_sum_exceeding::<Square>(puzzle_input)
+}
+
+// The first neighbour sum larger than the puzzle input on any lattice, and where it is written. A sum too large for
+// 32 bits is reported rather than written, since the memory could not hold it
+fn first_sum_exceeding<L: Lattice>(puzzle_input: u32) -> anyhow::Result<(L::Point, u32)> {
+    // The puzzle asks for a value larger than the input, so fill memory until one reaches input + 1
+    let target = puzzle_input
+        .checked_add(1)
+        .ok_or_else(|| anyhow!("No value can be larger than {}", puzzle_input))?;
+
+    let overflowed = Rc::new(Cell::new(false));
+    let overflow = Rc::clone(&overflowed);
+    let value_fn = move |_: &u32, point: &L::Point, memory: &dyn Memory<L>| {
+        checked_adjacent_sum(point, memory).unwrap_or_else(|| {
+            overflow.set(true);
+            0
+        })
+    };
+    let memory: GridMemory<L> = init_memory();
+    let populated_memory = populate_memory_until(memory, &value_fn, |last_value, _| {
+        last_value >= target || overflowed.get()
+    });
+
+    if overflowed.get() {
+        return Err(anyhow!(
+            "The first value larger than {} does not fit in 32 bits",
+            puzzle_input
+        ));
+    }
+    let found = populated_memory.points().find(|(_, value)| *value >= target);
+    found.ok_or_else(|| anyhow!("No value larger than {} was written to memory", puzzle_input))
+}
+
+// Empty memory of any kind, with 1 written to the access port
+pub fn init_memory<L: Lattice, M: Memory<L> + Default>() -> M {
+    let mut memory = M::default();
+    memory.insert(L::origin(), 1);
+    memory
+}
+
+pub fn populate_memory<L: Lattice, M: Memory<L>>(coordinate_system: M, max_value: u32, value_fn: &ValueFn<L>) -> M {
+    populate_memory_until(coordinate_system, value_fn, |last_value, _| last_value >= max_value)
+}
+
+// Walk the spiral on any lattice until `done` holds for the last value written and the memory so far
+pub fn populate_memory_until<L: Lattice, M: Memory<L>, F: Fn(u32, &M) -> bool>(
+    mut coordinate_system: M,
+    value_fn: &ValueFn<L>,
+    done: F,
+) -> M {
+    let mut last_direction = L::first_heading();
+    let mut last_coordinate = L::origin();
+    let mut last_value = 1;
+
+    while !done(last_value, &coordinate_system) {
+        // Calculate the next coordinate given the last coordinate and direction
+        last_coordinate = L::step(&last_direction, &last_coordinate);
+        last_value = value_fn(&last_value, &last_coordinate, &coordinate_system);
+
+        match coordinate_system.insert(last_coordinate, last_value) {
+            None => (),
+            Some(_) => panic!("Overwrote value in memory grid!"),
+        };
+
+        // Turn left where no value is written yet after the turn
+        last_direction = L::turn(&last_direction, &last_coordinate, |point| {
+            coordinate_system.contains(point)
+        });
+    }
+
+    coordinate_system
+}
+
+// Calculate the value as last value + 1 for D3P1. Part 1 is solved by `square_to_point` instead, but this numbers
+// the squares when drawing the spiral
+pub fn add_one<L: Lattice>(last_value: &u32, _: &L::Point, _: &dyn Memory<L>) -> u32 {
+    *last_value + 1
+}
+
+// Sum the values of the adjacent points as value D3P2. Panics if the sum does not fit in 32 bits, which
+// `find_first_value_exceeding_puzzle_input` reports as an error instead
+pub fn adjacent_sum<L: Lattice>(_: &u32, point: &L::Point, memory: &dyn Memory<L>) -> u32 {
+    checked_adjacent_sum(point, memory).expect("The sum of the adjacent values does not fit in 32 bits")
+}
+
+// Sum the values of the adjacent points, or None if the sum does not fit in 32 bits
+pub fn checked_adjacent_sum<L: Lattice>(point: &L::Point, memory: &dyn Memory<L>) -> Option<u32> {
+    let adjacent_points = L::neighbours(point);
+
+    adjacent_points
+        .iter()
+        .filter_map(|point| memory.get(point))
+        .try_fold(0u32, |sum, value| sum.checked_add(value))
+}
+
+fn left_turn_direction(direction: &Direction) -> Direction {
+    match direction {
+        Direction::East => Direction::North,
+        Direction::North => Direction::West,
+        Direction::West => Direction::South,
+        Direction::South => Direction::East,
+    }
+}
+
+fn right_turn_direction(direction: &Direction) -> Direction {
+    match direction {
+        Direction::East => Direction::South,
+        Direction::South => Direction::West,
+        Direction::West => Direction::North,
+        Direction::North => Direction::East,
+    }
+}
+
+fn calc_next_coordinate(direction: &Direction, point: &Point) -> Point {
+    match (direction, point) {
+        (Direction::East, (x, y)) => (x + 1, *y),
+        (Direction::North, (x, y)) => (*x, y + 1),
+        (Direction::West, (x, y)) => (x - 1, *y),
+        (Direction::South, (x, y)) => (*x, y - 1),
+    }
+}
+
+fn calc_adjacent_points((x, y): &Point) -> [Point; 8] {
+    [
+        (x + 1, *y),
+        (x + 1, y + 1),
+        (*x, y + 1),
+        (x - 1, y + 1),
+        (x - 1, *y),
+        (x - 1, y - 1),
+        (*x, y - 1),
+        (x + 1, y - 1),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use memory::HashMemory;
+
+    #[test]
+    fn test_left_turn_direction() {
+        assert_eq!(left_turn_direction(&Direction::East), Direction::North);
+        assert_eq!(left_turn_direction(&Direction::North), Direction::West);
+        assert_eq!(left_turn_direction(&Direction::West), Direction::South);
+        assert_eq!(left_turn_direction(&Direction::South), Direction::East);
+    }
+
+    #[test]
+    fn test_right_turn_direction() {
+        assert_eq!(right_turn_direction(&Direction::East), Direction::South);
+        assert_eq!(right_turn_direction(&Direction::South), Direction::West);
+        assert_eq!(right_turn_direction(&Direction::West), Direction::North);
+        assert_eq!(right_turn_direction(&Direction::North), Direction::East);
+    }
+
+    #[test]
+    fn test_next_coordinate() {
+        assert_eq!(calc_next_coordinate(&Direction::East, &(0, 0)), (1, 0));
+        assert_eq!(calc_next_coordinate(&Direction::North, &(0, 0)), (0, 1));
+        assert_eq!(calc_next_coordinate(&Direction::West, &(0, 0)), (-1, 0));
+        assert_eq!(calc_next_coordinate(&Direction::South, &(0, 0)), (0, -1));
+    }
+
+    #[test]
+    fn test_calc_adjacent_points() {
+        assert_eq!(
+            calc_adjacent_points(&(1, 1)),
+            [(2, 1), (2, 2), (1, 2), (0, 2), (0, 1), (0, 0), (1, 0), (2, 0)]
+        );
+        assert_eq!(
+            calc_adjacent_points(&(-1, -1)),
+            [(0, -1), (0, 0), (-1, 0), (-2, 0), (-2, -1), (-2, -2), (-1, -2), (0, -2)]
+        );
+    }
+
+    #[test]
+    fn maps_squares_to_points_like_the_simulation() {
+        let squares = 10_000;
+        let memory: HashMemory = populate_memory(init_memory(), squares, &add_one);
+        assert_eq!(memory.len(), squares as usize);
+
+        for (point, square) in memory.points() {
+            assert_eq!(square_to_point(square).unwrap(), point);
+            assert_eq!(point_to_square(point).unwrap(), square);
+        }
+    }
+
+    #[test]
+    fn maps_the_extremes() {
+        assert_eq!(square_to_point(1).unwrap(), (0, 0));
+        assert!(square_to_point(0).is_err());
+
+        let last = square_to_point(u32::MAX).unwrap();
+        assert_eq!(point_to_square(last).unwrap(), u32::MAX);
+        let next_ring = last.0.abs().max(last.1.abs()) + 1;
+        assert!(point_to_square((next_ring, 0)).is_err());
+        assert!(point_to_square((i32::MIN, i32::MAX)).is_err());
+    }
+
+    #[test]
+    fn memory_backends_agree() {
+        let grid: GridMemory = populate_memory(init_memory(), 1_000_000, &adjacent_sum);
+        let map: HashMemory = populate_memory(init_memory(), 1_000_000, &adjacent_sum);
+
+        let mut written: Vec<(Point, u32)> = grid.points().collect();
+        written.sort_unstable();
+        let mut expected: Vec<(Point, u32)> = map.points().collect();
+        expected.sort_unstable();
+        assert_eq!(written, expected);
+    }
+
+    #[test]
+    fn d3p2_unit_test() {
+        let mut memory: HashMemory = HashMemory::new();
+        memory.insert((0, 0), 1);
+        memory.insert((1, 0), 1);
+        memory.insert((1, 1), 2);
+        memory.insert((0, 1), 4);
+        memory.insert((-1, 1), 5);
+
+        assert_eq!(adjacent_sum(&0, &(-1, 0), &memory), 10);
+        memory.insert((-1, -1), u32::MAX);
+        assert_eq!(checked_adjacent_sum(&(-1, 0), &memory), None);
+    }
+
+    #[test]
+    fn rejects_invalid_puzzle_inputs() {
+        assert_eq!(
+            parse_input("\n  -5\n"),
+            Err(ParseError::new(2, 3, "Expected a square number, found \"-5\""))
+        );
+        assert!(find_memory_location_with_puzzle_input(0).is_err());
+
+        // The first value larger than this is 4429173742, which does not fit in 32 bits
+        let error = find_first_value_exceeding_puzzle_input(u32::MAX - 1).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The first value larger than 4294967294 does not fit in 32 bits"
+        );
+        assert!(find_first_value_exceeding_puzzle_input(u32::MAX).is_err());
+    }
+}
@@ -0,0 +1,539 @@
+use super::{
+    calc_adjacent_points, calc_next_coordinate, first_sum_exceeding, left_turn_direction, right_turn_direction,
+    spiral_point, spiral_square, Direction, Point,
+};
+use std::fmt;
+use std::hash::Hash;
+
+// A grid the spiral can be walked on: where it starts, how it steps and turns, which points touch, how far a point
+// is from the start, and where each numbered square lies without walking there. Lattices are plain markers, so value
+// functions generic over them live as long as they need to
+pub trait Lattice: 'static {
+    type Point: Copy + Eq + Hash + fmt::Debug;
+
+    // Which way the spiral is going
+    type Heading: Copy;
+
+    fn origin() -> Self::Point;
+
+    // The heading of the first step away from the origin
+    fn first_heading() -> Self::Heading;
+
+    fn step(heading: &Self::Heading, point: &Self::Point) -> Self::Point;
+
+    // The heading after writing to `point`, turning where `written` says the spiral has or has not been yet
+    fn turn<F: Fn(&Self::Point) -> bool>(heading: &Self::Heading, point: &Self::Point, written: F) -> Self::Heading;
+
+    // Every point touching this one, which `neighbors8` and the neighbour sum of D3P2 add up
+    fn neighbours(point: &Self::Point) -> Vec<Self::Point>;
+
+    // The neighbours sharing a side with the point, which `neighbors4` adds up
+    fn sides(point: &Self::Point) -> Vec<Self::Point>;
+
+    // The fewest steps from the origin to the point
+    fn distance(point: &Self::Point) -> u32;
+
+    // The point of any square from 1 up to 2^62
+    fn square_to_point(square: u64) -> Self::Point;
+
+    // The number of the square at a point, or None for points so far out their squares may not fit in 62 bits
+    fn point_to_square(point: &Self::Point) -> Option<u64>;
+
+    // The number of the last square of the ring around the origin the point lies on
+    fn ring_end(point: &Self::Point) -> u64;
+}
+
+// The square grid of the puzzle, where the spiral steps east, north, west and south and the distance is the
+// Manhattan distance
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Square;
+
+// A grid of hexagons in axial coordinates, where each point touches six others
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hexagonal;
+
+// A 3D grid of cubes, walked one cubic shell around the origin at a time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cubic;
+
+pub type Point3 = (i32, i32, i32);
+
+// The axial steps around a hexagon, each one a left turn from the one before
+const HEX_DIRECTIONS: [Point; 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+impl Lattice for Square {
+    type Point = Point;
+    type Heading = Direction;
+
+    fn origin() -> Point {
+        (0, 0)
+    }
+
+    fn first_heading() -> Direction {
+        Direction::East
+    }
+
+    fn step(direction: &Direction, point: &Point) -> Point {
+        calc_next_coordinate(direction, point)
+    }
+
+    // Turn left whenever nothing is written to the left yet
+    fn turn<F: Fn(&Point) -> bool>(direction: &Direction, point: &Point, written: F) -> Direction {
+        let left_turned_direction = left_turn_direction(direction);
+        match written(&calc_next_coordinate(&left_turned_direction, point)) {
+            true => *direction,
+            false => left_turned_direction,
+        }
+    }
+
+    fn neighbours(point: &Point) -> Vec<Point> {
+        calc_adjacent_points(point).to_vec()
+    }
+
+    fn sides(point: &Point) -> Vec<Point> {
+        // The adjacent points start to the east and go round anticlockwise, so every other one shares a side
+        calc_adjacent_points(point).iter().step_by(2).copied().collect()
+    }
+
+    fn distance((x, y): &Point) -> u32 {
+        x.unsigned_abs() + y.unsigned_abs()
+    }
+
+    fn square_to_point(square: u64) -> Point {
+        spiral_point(square)
+    }
+
+    fn point_to_square(point: &Point) -> Option<u64> {
+        spiral_square(*point)
+    }
+
+    fn ring_end((x, y): &Point) -> u64 {
+        let ring = u64::from(x.unsigned_abs().max(y.unsigned_abs()));
+        (2 * ring + 1).pow(2)
+    }
+}
+
+// Ring k around the origin holds 6k hexagons. Its j-th side runs from the corner k steps in direction j to the one
+// in direction j + 1, stepping in direction j + 2, so the hexagons of the ring are k·d(j) + t·d(j + 2) for t from 1
+// to k, side after side. Each ring ends k steps in direction 0, one step in direction 1 from where the next begins
+impl Lattice for Hexagonal {
+    type Point = Point;
+
+    // An index into `HEX_DIRECTIONS`
+    type Heading = usize;
+
+    fn origin() -> Point {
+        (0, 0)
+    }
+
+    fn first_heading() -> usize {
+        1
+    }
+
+    fn step(&direction: &usize, (q, r): &Point) -> Point {
+        let (dq, dr) = HEX_DIRECTIONS[direction];
+        (q + dq, r + dr)
+    }
+
+    // A hexagon turns by two directions to wrap round the corner of the ring inside it, so take the sharpest left
+    // turn of up to two that leads somewhere not written yet
+    fn turn<F: Fn(&Point) -> bool>(&direction: &usize, point: &Point, written: F) -> usize {
+        [2, 1]
+            .iter()
+            .map(|turn| (direction + turn) % HEX_DIRECTIONS.len())
+            .find(|turned| !written(&Self::step(turned, point)))
+            .unwrap_or(direction)
+    }
+
+    fn neighbours(point: &Point) -> Vec<Point> {
+        (0..HEX_DIRECTIONS.len())
+            .map(|direction| Self::step(&direction, point))
+            .collect()
+    }
+
+    fn sides(point: &Point) -> Vec<Point> {
+        Self::neighbours(point)
+    }
+
+    fn distance(&(q, r): &Point) -> u32 {
+        let (q, r) = (i64::from(q), i64::from(r));
+        ((q.abs() + r.abs() + (q + r).abs()) / 2) as u32
+    }
+
+    fn square_to_point(square: u64) -> Point {
+        if square == 1 {
+            return (0, 0);
+        }
+
+        // The ring ending at or after the square, from the estimate k ≈ √(n / 3)
+        let square = square as i64;
+        let mut ring = ((square - 1) as u64 / 3).isqrt() as i64;
+        while hex_ring_end(ring) < square {
+            ring += 1;
+        }
+
+        let along = square - hex_ring_end(ring - 1) - 1;
+        let (side, steps) = ((along / ring) as usize, along % ring + 1);
+        let ((cq, cr), (dq, dr)) = (HEX_DIRECTIONS[side], HEX_DIRECTIONS[(side + 2) % 6]);
+        let (cq, cr, dq, dr) = (i64::from(cq), i64::from(cr), i64::from(dq), i64::from(dr));
+        ((ring * cq + steps * dq) as i32, (ring * cr + steps * dr) as i32)
+    }
+
+    fn point_to_square(point: &Point) -> Option<u64> {
+        let ring = i64::from(Self::distance(point));
+        if ring == 0 {
+            return Some(1);
+        }
+        if ring >= 1 << 30 {
+            return None;
+        }
+
+        let (q, r) = (i64::from(point.0), i64::from(point.1));
+        (0..6).find_map(|side| {
+            let ((cq, cr), (dq, dr)) = (HEX_DIRECTIONS[side], HEX_DIRECTIONS[(side + 2) % 6]);
+            let (eq, er) = (q - ring * i64::from(cq), r - ring * i64::from(cr));
+
+            // How far along the side the point is, if it is on this side at all
+            let steps = if dq != 0 {
+                eq * i64::from(dq)
+            } else {
+                er * i64::from(dr)
+            };
+            let on_side = (1..=ring).contains(&steps) && (eq, er) == (steps * i64::from(dq), steps * i64::from(dr));
+            on_side.then(|| (hex_ring_end(ring - 1) + side as i64 * ring + steps) as u64)
+        })
+    }
+
+    fn ring_end(point: &Point) -> u64 {
+        hex_ring_end(i64::from(Self::distance(point))) as u64
+    }
+}
+
+// The number of the last hexagon within `ring` steps of the origin
+fn hex_ring_end(ring: i64) -> i64 {
+    3 * ring * (ring + 1) + 1
+}
+
+// Which way the cubic walk is going: straight up or down onto the next layer or shell, up or down onto the start of
+// the next ring, spiralling out across a layer or round a ring, or spiralling in across the last layer of a shell
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CubicHeading {
+    Vertical(i32),
+    Climb(i32),
+    Out(Direction),
+    In(Direction),
+}
+
+// Shell r is the surface of the cube reaching r steps from the origin. Odd shells are walked from the bottom up and
+// even ones from the top down, so each starts next to where the one inside it ends. The first layer of a shell spirals
+// out from its centre as the 2D spiral does, each layer between walks the outer ring of that spiral starting one step
+// along from where the layer before ended, and the last layer spirals in from its corner to its centre. Every step
+// goes to a neighbour, and every shell is finished before the next one starts
+impl Lattice for Cubic {
+    type Point = Point3;
+    type Heading = CubicHeading;
+
+    fn origin() -> Point3 {
+        (0, 0, 0)
+    }
+
+    fn first_heading() -> CubicHeading {
+        CubicHeading::Vertical(-1)
+    }
+
+    fn step(heading: &CubicHeading, &(x, y, z): &Point3) -> Point3 {
+        match heading {
+            CubicHeading::Vertical(dz) => (x, y, z + dz),
+            CubicHeading::Climb(dz) => (x, y + 1, z + dz),
+            CubicHeading::Out(direction) | CubicHeading::In(direction) => {
+                let (x, y) = calc_next_coordinate(direction, &(x, y));
+                (x, y, z)
+            }
+        }
+    }
+
+    fn turn<F: Fn(&Point3) -> bool>(heading: &CubicHeading, &(x, y, z): &Point3, written: F) -> CubicHeading {
+        let radius = x.abs().max(y.abs()).max(z.abs());
+        let up = cubic_shell_up(radius);
+        let corner = (x, y) == (radius, -radius);
+
+        match heading {
+            // Arriving on a layer: at the centre of the first one, the start of a ring, or the corner of the last one
+            CubicHeading::Vertical(_) | CubicHeading::Climb(_) => match (x, y) {
+                (0, 0) => CubicHeading::Out(Direction::East),
+                _ if corner => CubicHeading::In(Direction::West),
+                _ => CubicHeading::Out(Direction::North),
+            },
+            // The layer ends in its corner, under or over the corner of the last layer or the start of the next ring
+            CubicHeading::Out(_) if corner => match z + up == up * radius {
+                true => CubicHeading::Vertical(up),
+                false => CubicHeading::Climb(up),
+            },
+            // Turn left whenever nothing is written to the left yet, as on the square lattice
+            CubicHeading::Out(direction) => {
+                let (left_x, left_y) = calc_next_coordinate(&left_turn_direction(direction), &(x, y));
+                match written(&(left_x, left_y, z)) {
+                    true => *heading,
+                    false => CubicHeading::Out(left_turn_direction(direction)),
+                }
+            }
+            // The centre of the last layer is next to the centre of the next shell
+            CubicHeading::In(_) if (x, y) == (0, 0) => CubicHeading::Vertical(up),
+            // Turn right wherever the way ahead is written or leaves the layer
+            CubicHeading::In(direction) => {
+                let (ahead_x, ahead_y) = calc_next_coordinate(direction, &(x, y));
+                match ahead_x.abs().max(ahead_y.abs()) > radius || written(&(ahead_x, ahead_y, z)) {
+                    true => CubicHeading::In(right_turn_direction(direction)),
+                    false => *heading,
+                }
+            }
+        }
+    }
+
+    fn neighbours(&(x, y, z): &Point3) -> Vec<Point3> {
+        (-1..=1)
+            .flat_map(|dx| (-1..=1).flat_map(move |dy| (-1..=1).map(move |dz| (dx, dy, dz))))
+            .filter(|step| *step != (0, 0, 0))
+            .map(|(dx, dy, dz)| (x + dx, y + dy, z + dz))
+            .collect()
+    }
+
+    fn sides(&(x, y, z): &Point3) -> Vec<Point3> {
+        vec![
+            (x + 1, y, z),
+            (x - 1, y, z),
+            (x, y + 1, z),
+            (x, y - 1, z),
+            (x, y, z + 1),
+            (x, y, z - 1),
+        ]
+    }
+
+    fn distance((x, y, z): &Point3) -> u32 {
+        x.unsigned_abs() + y.unsigned_abs() + z.unsigned_abs()
+    }
+
+    fn square_to_point(square: u64) -> Point3 {
+        let radius = (cube_root(square - 1) as i64 + 1) / 2;
+        let up = i64::from(cubic_shell_up(radius as i32));
+        let (inner, outer) = ((2 * radius - 1).max(0), 2 * radius + 1);
+        let (layer, ring) = (outer * outer, outer * outer - inner * inner);
+
+        // Squares into the shell, then into the first layer, the layers between or the last layer
+        let along = square as i64 - inner.pow(3);
+        let (plane, z) = match along - layer {
+            into_middle if into_middle <= 0 => (along, -up * radius),
+            into_middle if into_middle <= (inner * ring) => (
+                inner * inner + (into_middle - 1) % ring + 1,
+                up * ((into_middle - 1) / ring - radius + 1),
+            ),
+            into_middle => (layer + 1 - (into_middle - inner * ring), up * radius),
+        };
+
+        let (x, y) = spiral_point(plane as u64);
+        (x, y, z as i32)
+    }
+
+    fn point_to_square(&(x, y, z): &Point3) -> Option<u64> {
+        let radius = i64::from(x.unsigned_abs().max(y.unsigned_abs()).max(z.unsigned_abs()));
+        if radius >= 1 << 19 {
+            return None;
+        }
+
+        let up = i64::from(cubic_shell_up(radius as i32));
+        let (inner, outer) = ((2 * radius - 1).max(0), 2 * radius + 1);
+        let (layer, ring) = (outer * outer, outer * outer - inner * inner);
+        let plane = spiral_square((x, y))? as i64;
+        let z = i64::from(z);
+
+        let along = match z {
+            z if z == -up * radius => plane,
+            z if z == up * radius => layer + inner * ring + layer + 1 - plane,
+            z => layer + (up * z + radius - 1) * ring + plane - inner * inner,
+        };
+        Some((inner.pow(3) + along) as u64)
+    }
+
+    fn ring_end(&(x, y, z): &Point3) -> u64 {
+        let radius = u64::from(x.unsigned_abs().max(y.unsigned_abs()).max(z.unsigned_abs()));
+        (2 * radius + 1).pow(3)
+    }
+}
+
+// Which way the walk goes from the first layer of a shell to the last
+fn cubic_shell_up(radius: i32) -> i32 {
+    match radius % 2 {
+        1 => 1,
+        _ => -1,
+    }
+}
+
+// The largest whole number whose cube is at most `n`, correcting the floating point estimate
+fn cube_root(n: u64) -> u64 {
+    let mut root = (n as f64).cbrt() as u64;
+    while root.pow(3) > n {
+        root -= 1;
+    }
+    while (root + 1).pow(3) <= n {
+        root += 1;
+    }
+    root
+}
+
+// D3P1 on any lattice: the distance from the access port to the numbered square
+pub fn lattice_distance<L: Lattice>(square: u32) -> anyhow::Result<u32> {
+    if square == 0 {
+        return Err(anyhow!("The squares are numbered from 1"));
+    }
+
+    Ok(L::distance(&L::square_to_point(u64::from(square))))
+}
+
+// D3P2 on any lattice: the first neighbour sum larger than the puzzle input
+pub fn lattice_first_value_exceeding<L: Lattice>(puzzle_input: u32) -> anyhow::Result<u32> {
+    first_sum_exceeding::<L>(puzzle_input).map(|(_, value)| value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_03::memory::HashMemory;
+    use crate::day_03::memory::Memory;
+    use crate::day_03::rule::Rule;
+    use crate::day_03::{add_one, find_first_value_exceeding_puzzle_input, find_memory_location_with_puzzle_input};
+    use crate::day_03::{adjacent_sum, init_memory, populate_memory_until, ValueFn};
+    use std::collections::HashSet;
+
+    // The points the walk visits and the values the value function writes to them, in the order they are written
+    fn written<L: Lattice>(squares: usize, value_fn: &ValueFn<L>) -> Vec<(L::Point, u32)> {
+        let memory: HashMemory<L> =
+            populate_memory_until(init_memory(), value_fn, |_, memory| memory.len() >= squares);
+        let mut written: Vec<(L::Point, u32)> = memory.points().collect();
+        written.sort_unstable_by_key(|(point, _)| L::point_to_square(point));
+        written
+    }
+
+    // The walk steps from neighbour to neighbour and numbers every point the way the closed forms do
+    fn assert_walk_matches_the_closed_forms<L: Lattice>(squares: usize) {
+        let walk = written::<L>(squares, &add_one);
+        assert_eq!(walk.len(), squares);
+
+        for pair in walk.windows(2) {
+            assert!(L::neighbours(&pair[0].0).contains(&pair[1].0));
+        }
+
+        for (point, square) in walk {
+            assert_eq!(L::square_to_point(u64::from(square)), point);
+            assert_eq!(L::point_to_square(&point), Some(u64::from(square)));
+        }
+    }
+
+    #[test]
+    fn square_lattice_matches_the_puzzle() {
+        assert_walk_matches_the_closed_forms::<Square>(10_000);
+
+        for input in [1, 12, 23, 1024, 368_078, u32::MAX] {
+            assert_eq!(
+                lattice_distance::<Square>(input).unwrap() as i32,
+                find_memory_location_with_puzzle_input(input).unwrap()
+            );
+        }
+        for input in [5, 133, 747, 368_078] {
+            assert_eq!(
+                lattice_first_value_exceeding::<Square>(input).unwrap(),
+                find_first_value_exceeding_puzzle_input(input).unwrap().1
+            );
+        }
+
+        assert!(lattice_distance::<Square>(0).is_err());
+        assert!(lattice_first_value_exceeding::<Square>(u32::MAX - 1).is_err());
+    }
+
+    #[test]
+    fn hexagonal_spiral_fills_ring_after_ring() {
+        assert_walk_matches_the_closed_forms::<Hexagonal>(10_000);
+        let walk: Vec<Point> = written::<Hexagonal>(1 + 6 + 12 + 18 + 24, &add_one)
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect();
+
+        // Ring k holds 6k hexagons, and no hexagon is visited twice
+        let distances: Vec<u32> = walk.iter().map(Hexagonal::distance).collect();
+        let mut expected = vec![0];
+        for ring in 1..=4 {
+            expected.extend(std::iter::repeat_n(ring, 6 * ring as usize));
+        }
+        assert_eq!(distances, expected);
+
+        assert_eq!(walk.iter().collect::<HashSet<_>>().len(), walk.len());
+
+        // Around the origin each hexagon touches the origin and the one before it, until the second ring starts
+        let values: Vec<u32> = written::<Hexagonal>(13, &adjacent_sum)
+            .iter()
+            .map(|(_, value)| *value)
+            .collect();
+        assert_eq!(values, vec![1, 1, 2, 3, 4, 5, 7, 8, 9, 12, 14, 19, 22]);
+        assert_eq!(lattice_first_value_exceeding::<Hexagonal>(20).unwrap(), 22);
+        assert!(lattice_first_value_exceeding::<Hexagonal>(u32::MAX - 1).is_err());
+        assert_eq!(lattice_distance::<Hexagonal>(u32::MAX).unwrap(), 37_837);
+    }
+
+    #[test]
+    fn cubic_walk_finishes_each_shell_first() {
+        assert_walk_matches_the_closed_forms::<Cubic>(10_000);
+        let walk: Vec<Point3> = written::<Cubic>(125, &add_one)
+            .into_iter()
+            .map(|(point, _)| point)
+            .collect();
+        let shells: Vec<i32> = walk.iter().map(|(x, y, z)| x.abs().max(y.abs()).max(z.abs())).collect();
+
+        // Odd shells are walked from the bottom up and even ones from the top down
+        assert_eq!(&walk[..3], &[(0, 0, 0), (0, 0, -1), (1, 0, -1)]);
+        assert_eq!(walk[10], (1, 0, 0));
+        assert_eq!(
+            &walk[18..28],
+            &[
+                (1, -1, 1),
+                (0, -1, 1),
+                (-1, -1, 1),
+                (-1, 0, 1),
+                (-1, 1, 1),
+                (0, 1, 1),
+                (1, 1, 1),
+                (1, 0, 1),
+                (0, 0, 1),
+                (0, 0, 2)
+            ]
+        );
+        assert!(shells[1..27].iter().all(|shell| *shell == 1));
+        assert!(shells[27..].iter().all(|shell| *shell == 2));
+        assert_eq!(walk.iter().collect::<HashSet<_>>().len(), 125);
+
+        assert_eq!(Cubic::distance(&(1, -1, 1)), 3);
+        assert_eq!(Cubic::neighbours(&(0, 0, 0)).len(), 26);
+        assert_eq!(lattice_distance::<Cubic>(19).unwrap(), 3);
+        assert_eq!(lattice_distance::<Cubic>(27).unwrap(), 1);
+        assert_eq!(lattice_first_value_exceeding::<Cubic>(1).unwrap(), 2);
+    }
+
+    #[test]
+    fn rules_follow_the_lattice() {
+        let rule_values = |rule: &str| -> Vec<u32> {
+            let value_fn = rule.parse::<Rule>().unwrap().into_value_fn::<Hexagonal>();
+            written::<Hexagonal>(7, &*value_fn)
+                .iter()
+                .map(|(_, value)| *value)
+                .collect()
+        };
+
+        // Every neighbour of a hexagon shares a side with it, so none are diagonal
+        assert_eq!(rule_values("sum(neighbors4)"), rule_values("sum(neighbors8)"));
+        assert_eq!(rule_values("count(diagonals) + index"), vec![1, 2, 3, 4, 5, 6, 7]);
+
+        let corner = (1, 1, 1);
+        assert_eq!(Cubic::sides(&corner).len(), 6);
+        assert!(Cubic::sides(&corner)
+            .iter()
+            .all(|side| Cubic::neighbours(&corner).contains(side)));
+    }
+}
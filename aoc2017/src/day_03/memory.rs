@@ -0,0 +1,196 @@
+use super::lattice::{Lattice, Square};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+// Where the spiral keeps the values written to each point of the lattice
+pub trait Memory<L: Lattice = Square> {
+    fn get(&self, point: &L::Point) -> Option<u32>;
+
+    // Write a value, returning the one it replaced
+    fn insert(&mut self, point: L::Point, value: u32) -> Option<u32>;
+
+    fn len(&self) -> usize;
+
+    // Every point written to, in no particular order
+    fn points(&self) -> Box<dyn Iterator<Item = (L::Point, u32)> + '_>;
+
+    fn contains(&self, point: &L::Point) -> bool {
+        self.get(point).is_some()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+// Memory as a map from points to values, which only stores the points written to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HashMemory<L: Lattice = Square> {
+    values: HashMap<L::Point, u32>,
+}
+
+impl<L: Lattice> HashMemory<L> {
+    pub fn new() -> HashMemory<L> {
+        HashMemory { values: HashMap::new() }
+    }
+}
+
+impl<L: Lattice> Default for HashMemory<L> {
+    fn default() -> HashMemory<L> {
+        HashMemory::new()
+    }
+}
+
+impl<L: Lattice> Memory<L> for HashMemory<L> {
+    fn get(&self, point: &L::Point) -> Option<u32> {
+        self.values.get(point).copied()
+    }
+
+    fn insert(&mut self, point: L::Point, value: u32) -> Option<u32> {
+        self.values.insert(point, value)
+    }
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn points(&self) -> Box<dyn Iterator<Item = (L::Point, u32)> + '_> {
+        Box::new(self.values.iter().map(|(point, value)| (*point, *value)))
+    }
+}
+
+// Memory as an array of the points in the order the spiral numbers them, which grows one ring around the access
+// port at a time. Growing only ever appends, the spiral fills every square of a ring before the next, and looking up
+// a square is a bit of arithmetic rather than hashing. Cells hold plain values, and one bit per cell says whether it
+// was written to, so every u32 can be stored
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GridMemory<L: Lattice = Square> {
+    cells: Vec<u32>,
+    written: Vec<u64>,
+    len: usize,
+    lattice: PhantomData<L>,
+}
+
+impl<L: Lattice> GridMemory<L> {
+    pub fn new() -> GridMemory<L> {
+        GridMemory {
+            cells: Vec::new(),
+            written: Vec::new(),
+            len: 0,
+            lattice: PhantomData,
+        }
+    }
+
+    // The position of the point in the spiral, or None when it lies beyond any array this platform can hold
+    fn index(point: &L::Point) -> Option<usize> {
+        L::point_to_square(point).and_then(|square| usize::try_from(square - 1).ok())
+    }
+
+    fn value_at(&self, index: usize) -> Option<u32> {
+        let written = self.written.get(index / 64)? & (1 << (index % 64)) != 0;
+        written.then(|| self.cells[index])
+    }
+}
+
+impl<L: Lattice> Default for GridMemory<L> {
+    fn default() -> GridMemory<L> {
+        GridMemory::new()
+    }
+}
+
+impl<L: Lattice> Memory<L> for GridMemory<L> {
+    fn get(&self, point: &L::Point) -> Option<u32> {
+        GridMemory::<L>::index(point).and_then(|index| self.value_at(index))
+    }
+
+    fn insert(&mut self, point: L::Point, value: u32) -> Option<u32> {
+        let index =
+            GridMemory::<L>::index(&point).expect("The point lies beyond the memory this platform can address");
+        if index >= self.cells.len() {
+            // Grow to the end of the point's ring and no further, which is the next ring while filling the spiral
+            let end = usize::try_from(L::ring_end(&point)).expect("The ring ends after a square this platform holds");
+            self.cells.reserve_exact(end - self.cells.len());
+            self.cells.resize(end, 0);
+            let words = end.div_ceil(64);
+            self.written.reserve_exact(words - self.written.len());
+            self.written.resize(words, 0);
+        }
+
+        let previous = self.value_at(index);
+        self.cells[index] = value;
+        self.written[index / 64] |= 1 << (index % 64);
+        if previous.is_none() {
+            self.len += 1;
+        }
+        previous
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn points(&self) -> Box<dyn Iterator<Item = (L::Point, u32)> + '_> {
+        Box::new((0..self.cells.len()).filter_map(move |index| {
+            self.value_at(index)
+                .map(|value| (L::square_to_point(index as u64 + 1), value))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_03::spiral_point;
+    use crate::day_03::Point;
+
+    #[test]
+    fn grid_grows_around_the_access_port() {
+        let mut grid: GridMemory = GridMemory::new();
+        let mut map: HashMemory = HashMemory::new();
+        let points = [
+            (0, 0),
+            (1, 0),
+            (-3, 2),
+            (0, 0),
+            (7, -7),
+            (-20, 19),
+            (5, 5),
+            (5, 5),
+            (2, -2),
+        ];
+        let values = [0, 1, 2, 3, 4, 5, u32::MAX, 6, u32::MAX];
+
+        for (point, value) in points.iter().zip(values) {
+            assert_eq!(grid.insert(*point, value), map.insert(*point, value));
+        }
+
+        assert_eq!(grid.len(), 7);
+        assert_eq!(grid.cells.len(), 41 * 41);
+        assert_eq!(grid.get(&(0, 0)), Some(3));
+        assert_eq!(grid.get(&(-3, 2)), Some(2));
+        assert_eq!(grid.get(&(2, -2)), Some(u32::MAX));
+        assert_eq!(grid.get(&(4, 4)), None);
+        assert_eq!(grid.get(&(100, 0)), None);
+        assert_eq!(grid.get(&(i32::MIN, i32::MAX)), None);
+
+        let mut written: Vec<(Point, u32)> = grid.points().collect();
+        written.sort_unstable();
+        let mut expected: Vec<(Point, u32)> = map.points().collect();
+        expected.sort_unstable();
+        assert_eq!(written, expected);
+    }
+
+    #[test]
+    fn indexes_squares_beyond_32_bits() {
+        // Past 2^31 squares the index no longer fits in an i32, and past 2^32 the square is no longer a u32
+        for square in [1 << 31, (1 << 32) + 7, 40_000 * 40_000 + 3] {
+            let point = spiral_point(square);
+            assert_eq!(GridMemory::<Square>::index(&point), Some(square as usize - 1));
+        }
+        assert_eq!(
+            GridMemory::<Square>::index(&(30_000, -30_000)),
+            Some(60_001 * 60_001 - 1)
+        );
+    }
+}
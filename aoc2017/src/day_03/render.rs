@@ -0,0 +1,200 @@
+use super::memory::Memory;
+use super::{point_to_square, Point};
+use std::fmt::Write;
+
+// Pixels per square in the SVG, grown when the values need more room
+const SQUARE_SIZE: usize = 40;
+
+// A rectangle of the grid to draw. As in the spiral, x grows to the right and y grows upwards
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Window {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Window {
+    pub fn around((x, y): Point, radius: i32) -> Window {
+        Window {
+            min: (x - radius, y - radius),
+            max: (x + radius, y + radius),
+        }
+    }
+
+    // The smallest window showing everything written to memory
+    pub fn covering(memory: &dyn Memory) -> Window {
+        let xs = || memory.points().map(|(point, _)| point.0);
+        let ys = || memory.points().map(|(point, _)| point.1);
+
+        Window {
+            min: (xs().min().unwrap_or(0), ys().min().unwrap_or(0)),
+            max: (xs().max().unwrap_or(0), ys().max().unwrap_or(0)),
+        }
+    }
+
+    pub fn contains(&self, (x, y): &Point) -> bool {
+        (self.min.0..=self.max.0).contains(x) && (self.min.1..=self.max.1).contains(y)
+    }
+
+    fn width(&self) -> usize {
+        (self.max.0 - self.min.0 + 1).max(0) as usize
+    }
+
+    fn height(&self) -> usize {
+        (self.max.1 - self.min.1 + 1).max(0) as usize
+    }
+
+    // The points of each row, from the top row down
+    fn rows(&self) -> impl Iterator<Item = Vec<Point>> + '_ {
+        (self.min.1..=self.max.1)
+            .rev()
+            .map(move |y| (self.min.0..=self.max.0).map(|x| (x, y)).collect())
+    }
+}
+
+// Draw the window as a grid of right aligned values, with the access port in parentheses and the target in brackets.
+// Squares not written to memory are left blank
+pub fn render_text(memory: &dyn Memory, window: &Window, target: Option<Point>) -> String {
+    let width = values_in(memory, window)
+        .map(|(_, value)| value.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let mut text = String::new();
+
+    for row in window.rows() {
+        let cells: Vec<String> = row
+            .iter()
+            .map(|point| {
+                let value = memory.get(point).map_or_else(String::new, |value| value.to_string());
+                let (open, close) = match point {
+                    _ if Some(*point) == target => ('[', ']'),
+                    (0, 0) => ('(', ')'),
+                    _ => (' ', ' '),
+                };
+                format!("{}{:>width$}{}", open, value, close, width = width)
+            })
+            .collect();
+
+        text.push_str(cells.join(" ").trim_end());
+        text.push('\n');
+    }
+
+    text
+}
+
+// Draw the window as an SVG image, with the access port and target filled in and the walk from square to square
+// traced through the middle of each one
+pub fn render_svg(memory: &dyn Memory, window: &Window, target: Option<Point>) -> String {
+    let digits = values_in(memory, window)
+        .map(|(_, value)| value.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let size = SQUARE_SIZE.max(digits * 8 + 12);
+    let (width, height) = (window.width() * size, window.height() * size);
+
+    // The top left corner of the square at a point
+    let corner = |(x, y): &Point| ((x - window.min.0) as usize * size, (window.max.1 - y) as usize * size);
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+        width, height
+    )
+    .unwrap();
+    writeln!(
+        svg,
+        "<style>text {{ font: 12px monospace; text-anchor: middle; dominant-baseline: central }}</style>"
+    )
+    .unwrap();
+
+    for row in window.rows() {
+        for point in row {
+            let fill = match point {
+                _ if Some(point) == target => "#f4a261",
+                (0, 0) => "#8ecae6",
+                _ if memory.contains(&point) => "#ffffff",
+                _ => "#eeeeee",
+            };
+            let (left, top) = corner(&point);
+            writeln!(
+                svg,
+                r##"<rect x="{}" y="{}" width="{}" height="{}" fill="{}" stroke="#999999"/>"##,
+                left, top, size, size, fill
+            )
+            .unwrap();
+        }
+    }
+
+    let mut walk: Vec<(u32, Point)> = values_in(memory, window)
+        .filter_map(|(point, _)| point_to_square(point).ok().map(|square| (square, point)))
+        .collect();
+    walk.sort_unstable();
+
+    let mut path = String::new();
+    let mut previous: Option<u32> = None;
+    for (square, point) in &walk {
+        let (left, top) = corner(point);
+        // Squares are only joined when they follow each other, so the walk breaks where it leaves the window
+        let command = if previous.map(|previous| previous + 1) == Some(*square) {
+            'L'
+        } else {
+            'M'
+        };
+        write!(path, "{}{} {} ", command, left + size / 2, top + size / 2).unwrap();
+        previous = Some(*square);
+    }
+    writeln!(
+        svg,
+        r##"<path d="{}" fill="none" stroke="#e63946" stroke-width="2" stroke-opacity="0.6"/>"##,
+        path.trim_end()
+    )
+    .unwrap();
+
+    for (point, value) in values_in(memory, window) {
+        let (left, top) = corner(&point);
+        writeln!(
+            svg,
+            r#"<text x="{}" y="{}">{}</text>"#,
+            left + size / 2,
+            top + size / 2,
+            value
+        )
+        .unwrap();
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn values_in<'a>(memory: &'a dyn Memory, window: &'a Window) -> impl Iterator<Item = (Point, u32)> + 'a {
+    memory.points().filter(move |(point, _)| window.contains(point))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_03::memory::{GridMemory, HashMemory};
+    use crate::day_03::{add_one, adjacent_sum, init_memory, populate_memory};
+
+    #[test]
+    fn renders_an_aligned_grid() {
+        let memory: HashMemory = populate_memory(init_memory(), 23, &adjacent_sum);
+
+        assert_eq!(
+            render_text(&memory, &Window::around((0, 0), 1), Some((0, -1))),
+            "  5    4    2\n 10  ( 1)   1\n 11  [23]\n"
+        );
+    }
+
+    #[test]
+    fn draws_the_walk_through_the_window() {
+        let memory: GridMemory = populate_memory(init_memory(), 25, &add_one);
+        let svg = render_svg(&memory, &Window::around((0, 0), 1), Some((1, -1)));
+
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="120" height="120""#));
+        assert_eq!(svg.matches("<rect").count(), 9);
+        assert_eq!(svg.matches("<text").count(), 9);
+        assert!(svg.contains(r##"<rect x="80" y="80" width="40" height="40" fill="#f4a261""##));
+        assert!(svg.contains(r#"<path d="M60 60 L100 60 L100 20 L60 20 L20 20 L20 60 L20 100 L60 100 L100 100""#));
+    }
+}
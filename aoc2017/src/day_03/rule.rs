@@ -0,0 +1,352 @@
+use super::lattice::Lattice;
+use super::memory::Memory;
+use super::ValueFn;
+use crate::error::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+// A rule for the value written to each square of the spiral, parsed from text such as `sum(neighbors8)`,
+// `prev * 2` or `sum(neighbors4) + index`. Rules combine
+//
+// - whole numbers
+// - `prev`, the value written to the square before
+// - `index`, the number of the square, counting the access port as 1
+// - `sum`, `min`, `max` or `count` of the values already written to `neighbors8`, `neighbors4` or `diagonals`,
+//   which on other lattices are every neighbour, the neighbours sharing a side and the rest
+//
+// with `+`, `-`, `*`, `/`, `%` and parentheses. Arithmetic saturates instead of overflowing, and dividing by zero
+// gives 0
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rule {
+    source: String,
+    expression: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Expression {
+    Number(u64),
+    Prev,
+    Index,
+    Neighbours(Aggregate, Neighbourhood),
+    Binary(Operator, Box<Expression>, Box<Expression>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Aggregate {
+    Sum,
+    Min,
+    Max,
+    Count,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Neighbourhood {
+    All,
+    Orthogonal,
+    Diagonal,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl Rule {
+    // The value to write to the square at `point`, after `prev` was written to the square before
+    pub fn value<L: Lattice>(&self, prev: u32, point: &L::Point, memory: &dyn Memory<L>) -> u32 {
+        let value = self.expression.evaluate(prev, point, memory);
+        value.min(u64::from(u32::MAX)) as u32
+    }
+
+    pub fn into_value_fn<L: Lattice>(self) -> Box<ValueFn<L>> {
+        Box::new(move |prev, point, memory| self.value(*prev, point, memory))
+    }
+}
+
+impl Expression {
+    fn evaluate<L: Lattice>(&self, prev: u32, point: &L::Point, memory: &dyn Memory<L>) -> u64 {
+        match self {
+            Expression::Number(number) => *number,
+            Expression::Prev => u64::from(prev),
+            Expression::Index => L::point_to_square(point).unwrap_or(u64::MAX),
+            Expression::Neighbours(aggregate, neighbourhood) => {
+                let values = neighbourhood
+                    .points::<L>(point)
+                    .into_iter()
+                    .filter_map(|point| memory.get(&point))
+                    .map(u64::from);
+
+                match aggregate {
+                    Aggregate::Sum => values.sum(),
+                    Aggregate::Min => values.min().unwrap_or(0),
+                    Aggregate::Max => values.max().unwrap_or(0),
+                    Aggregate::Count => values.count() as u64,
+                }
+            }
+            Expression::Binary(operator, left, right) => {
+                let (left, right) = (left.evaluate(prev, point, memory), right.evaluate(prev, point, memory));
+                match operator {
+                    Operator::Add => left.saturating_add(right),
+                    Operator::Subtract => left.saturating_sub(right),
+                    Operator::Multiply => left.saturating_mul(right),
+                    Operator::Divide => left.checked_div(right).unwrap_or(0),
+                    Operator::Remainder => left.checked_rem(right).unwrap_or(0),
+                }
+            }
+        }
+    }
+}
+
+impl Neighbourhood {
+    fn points<L: Lattice>(&self, point: &L::Point) -> Vec<L::Point> {
+        match self {
+            Neighbourhood::All => L::neighbours(point),
+            Neighbourhood::Orthogonal => L::sides(point),
+            Neighbourhood::Diagonal => {
+                let sides = L::sides(point);
+                L::neighbours(point)
+                    .into_iter()
+                    .filter(|neighbour| !sides.contains(neighbour))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(&self.source)
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Rule, ParseError> {
+        let mut parser = Parser {
+            input: s,
+            tokens: tokenize(s)?,
+            position: 0,
+        };
+
+        let expression = parser.expression()?;
+        match parser.peek() {
+            None => Ok(Rule {
+                source: s.trim().to_owned(),
+                expression,
+            }),
+            Some(token) => Err(parser.error_at(token, "Expected an operator or the end of the rule")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Token<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+// Split the rule into words, numbers and single character symbols
+fn tokenize(input: &str) -> Result<Vec<Token<'_>>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((offset, c)) = chars.next() {
+        let mut end = offset + c.len_utf8();
+        match c {
+            c if c.is_whitespace() => continue,
+            '+' | '-' | '*' | '/' | '%' | '(' | ')' => (),
+            c if c.is_ascii_alphanumeric() => {
+                while let Some((next, c)) = chars.peek().copied() {
+                    if !c.is_ascii_alphanumeric() {
+                        break;
+                    }
+                    end = next + c.len_utf8();
+                    chars.next();
+                }
+            }
+            c => return Err(ParseError::at_offset(input, offset, format!("Unexpected {:?}", c))),
+        }
+        tokens.push(Token {
+            text: &input[offset..end],
+            offset,
+        });
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<Token<'a>>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let token = self.peek();
+        self.position += 1;
+        token
+    }
+
+    fn error_at(&self, token: Token, expected: &str) -> ParseError {
+        ParseError::at_offset(
+            self.input,
+            token.offset,
+            format!("{}, found {:?}", expected, token.text),
+        )
+    }
+
+    // An error for a missing token, pointing at the next one or the end of the rule
+    fn expected(&self, expected: &str) -> ParseError {
+        match self.peek() {
+            Some(token) => self.error_at(token, expected),
+            None => ParseError::at_offset(self.input, self.input.len(), format!("{}, found the end", expected)),
+        }
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), ParseError> {
+        match self.peek() {
+            Some(token) if token.text == text => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(self.expected(&format!("Expected {:?}", text))),
+        }
+    }
+
+    // Sums and differences of terms
+    fn expression(&mut self) -> Result<Expression, ParseError> {
+        let mut expression = self.term()?;
+        while let Some(operator) = self.operator(&[("+", Operator::Add), ("-", Operator::Subtract)]) {
+            expression = Expression::Binary(operator, Box::new(expression), Box::new(self.term()?));
+        }
+        Ok(expression)
+    }
+
+    // Products, quotients and remainders of factors
+    fn term(&mut self) -> Result<Expression, ParseError> {
+        let operators = [
+            ("*", Operator::Multiply),
+            ("/", Operator::Divide),
+            ("%", Operator::Remainder),
+        ];
+
+        let mut term = self.factor()?;
+        while let Some(operator) = self.operator(&operators) {
+            term = Expression::Binary(operator, Box::new(term), Box::new(self.factor()?));
+        }
+        Ok(term)
+    }
+
+    fn operator(&mut self, operators: &[(&str, Operator)]) -> Option<Operator> {
+        let token = self.peek()?;
+        let (_, operator) = operators.iter().find(|(text, _)| *text == token.text)?;
+        self.position += 1;
+        Some(*operator)
+    }
+
+    fn factor(&mut self) -> Result<Expression, ParseError> {
+        let token = match self.next() {
+            Some(token) => token,
+            None => return Err(self.expected("Expected a value")),
+        };
+
+        match token.text {
+            "(" => {
+                let expression = self.expression()?;
+                self.expect(")")?;
+                Ok(expression)
+            }
+            "prev" => Ok(Expression::Prev),
+            "index" => Ok(Expression::Index),
+            "sum" | "min" | "max" | "count" => {
+                let aggregate = match token.text {
+                    "sum" => Aggregate::Sum,
+                    "min" => Aggregate::Min,
+                    "max" => Aggregate::Max,
+                    _ => Aggregate::Count,
+                };
+
+                self.expect("(")?;
+                let neighbourhood = match self.peek().map(|token| token.text) {
+                    Some("neighbors8") => Neighbourhood::All,
+                    Some("neighbors4") => Neighbourhood::Orthogonal,
+                    Some("diagonals") => Neighbourhood::Diagonal,
+                    _ => return Err(self.expected("Expected neighbors8, neighbors4 or diagonals")),
+                };
+                self.position += 1;
+                self.expect(")")?;
+
+                Ok(Expression::Neighbours(aggregate, neighbourhood))
+            }
+            text if text.bytes().all(|byte| byte.is_ascii_digit()) => text
+                .parse()
+                .map(Expression::Number)
+                .map_err(|_| self.error_at(token, "Expected a number that fits in 64 bits")),
+            _ => Err(self.error_at(token, "Expected a value")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day_03::memory::GridMemory;
+    use crate::day_03::{add_one, adjacent_sum, init_memory, populate_memory_until, square_to_point};
+
+    // The values a rule writes to the first squares, in the order they are written
+    fn values(value_fn: &ValueFn, squares: usize) -> Vec<u32> {
+        let memory: GridMemory = populate_memory_until(init_memory(), value_fn, |_, memory| memory.len() >= squares);
+        (1..=squares as u32)
+            .map(|square| memory.get(&square_to_point(square).unwrap()).unwrap())
+            .collect()
+    }
+
+    fn rule_values(rule: &str, squares: usize) -> Vec<u32> {
+        let rule: Rule = rule.parse().unwrap();
+        values(&*rule.into_value_fn(), squares)
+    }
+
+    #[test]
+    fn matches_the_built_in_rules() {
+        assert_eq!(rule_values("prev + 1", 1000), values(&add_one, 1000));
+        assert_eq!(rule_values("sum(neighbors8)", 80), values(&adjacent_sum, 80));
+    }
+
+    #[test]
+    fn evaluates_rules() {
+        assert_eq!(rule_values("prev * 2", 5), vec![1, 2, 4, 8, 16]);
+        assert_eq!(rule_values("sum(neighbors4) + index", 4), vec![1, 3, 6, 11]);
+        assert_eq!(rule_values("count(diagonals)", 5), vec![1, 0, 1, 1, 1]);
+        assert_eq!(rule_values("1 + 2 * 3 - (1 + 2) * 3 % 4", 2), vec![1, 6]);
+        assert_eq!(rule_values("prev - 5 + prev / 0", 2), vec![1, 0]);
+        assert_eq!(rule_values("prev * 4294967295 * 2", 2), vec![1, u32::MAX]);
+    }
+
+    #[test]
+    fn reports_where_rules_are_invalid() {
+        let error = |rule: &str| rule.parse::<Rule>().unwrap_err().to_string();
+
+        assert_eq!(
+            error("sum(neighbours8)"),
+            "line 1, column 5: Expected neighbors8, neighbors4 or diagonals, found \"neighbours8\""
+        );
+        assert_eq!(error("prev +"), "line 1, column 7: Expected a value, found the end");
+        assert_eq!(
+            error("prev 2"),
+            "line 1, column 6: Expected an operator or the end of the rule, found \"2\""
+        );
+        assert_eq!(error("(prev"), "line 1, column 6: Expected \")\", found the end");
+        assert_eq!(error("prev # 1"), "line 1, column 6: Unexpected '#'");
+        assert_eq!(error("next"), "line 1, column 1: Expected a value, found \"next\"");
+    }
+}
@@ -0,0 +1,82 @@
+use crate::solution::Solution;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input).into_iter().map(String::from).collect())
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        Ok(input.iter().filter(|pw| validate_no_same_words(pw)).count())
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        Ok(input.iter().filter(|pw| validate_no_anagrams(pw)).count())
+    }
+}
+
+pub fn parse_input(string_input: &str) -> Vec<&str> {
+    string_input.trim().split('\n').collect()
+}
+
+// Validate: Are any two words in the password similar?
+pub fn validate_no_same_words(password: &str) -> bool {
+    let words: Vec<&str> = password.split(' ').collect();
+    let word_count = words.len();
+    let set: HashSet<&str> = HashSet::from_iter(words);
+
+    word_count == set.len()
+}
+
+// Validate: Are any two words anagrams?
+pub fn validate_no_anagrams(password: &str) -> bool {
+    let normalized_words: Vec<String> = password.split(' ').map(to_normalized_word).collect();
+    let normalized_word_count = normalized_words.len();
+    let set: HashSet<String> = HashSet::from_iter(normalized_words);
+
+    normalized_word_count == set.len()
+}
+
+// Having no clue what to call this, but take all chars in a word,
+// and sort them by char code, creating a way to identify anagrams
+fn to_normalized_word(word: &str) -> String {
+    let mut chars: Vec<char> = word.chars().collect();
+    chars.sort_unstable();
+    chars.iter().collect::<String>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_no_same_words() {
+        assert!(validate_no_same_words("aa bb cc dd ee"));
+        assert!(!validate_no_same_words("aa bb cc dd aa"));
+        assert!(validate_no_same_words("aa bb cc dd aaa"));
+    }
+
+    #[test]
+    fn test_validate_no_anagrams() {
+        assert!(validate_no_anagrams("abcde fghij"));
+        assert!(!validate_no_anagrams("abcde xyz ecdab"));
+        assert!(validate_no_anagrams("a ab abc abd abf abj"));
+        assert!(validate_no_anagrams("iiii oiii ooii oooi oooo"));
+        assert!(!validate_no_anagrams("oiii ioii iioi iiio"));
+    }
+
+    #[test]
+    fn test_to_normalized_word() {
+        assert_eq!(to_normalized_word("zzffuuqqaa"), "aaffqquuzz");
+        assert_eq!(to_normalized_word("mountaintop"), "aimnnoopttu");
+    }
+}
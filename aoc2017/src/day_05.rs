@@ -0,0 +1,108 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub type Program = Vec<i32>;
+pub type OffsetCalcFn = dyn Fn(&i32) -> i32;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Program;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        execute_program(input.clone(), &add_one_to_offset)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        execute_program(input.clone(), &add_or_subtract_offset)
+    }
+}
+
+// Parse one jump offset per line, ignoring blank lines
+pub fn parse_input(input: &str) -> Result<Program, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let offset = line.trim();
+            let column = line.len() - line.trim_start().len() + 1;
+
+            offset
+                .parse()
+                .map_err(|_| ParseError::new(index + 1, column, format!("Expected a jump offset, found {:?}", offset)))
+        })
+        .collect()
+}
+
+// Follow the jumps until one leads outside the program, in either direction
+pub fn execute_program(mut program: Program, offset_fn: &OffsetCalcFn) -> anyhow::Result<u32> {
+    let mut pointer: usize = 0;
+    let mut step: u32 = 0;
+
+    while pointer < program.len() {
+        let offset = program[pointer];
+        program[pointer] = offset_fn(&offset);
+        step = step
+            .checked_add(1)
+            .ok_or_else(|| anyhow!("The program did not exit within {} steps", u32::MAX))?;
+
+        match calc_next_pointer(&pointer, &offset) {
+            Some(next_pointer) => pointer = next_pointer,
+            None => break,
+        }
+    }
+
+    Ok(step)
+}
+
+// Calculate the next offset by adding 1 to the previous offset (D5P1)
+pub fn add_one_to_offset(offset: &i32) -> i32 {
+    offset + 1
+}
+
+// Calculate the next offset by subtracting 1 if the offset >= 3, otherwise add 1 (D5P2)
+pub fn add_or_subtract_offset(offset: &i32) -> i32 {
+    if *offset >= 3 {
+        offset - 1
+    } else {
+        offset + 1
+    }
+}
+
+// Calculate the next pointer value. Since a Vec is indexed by usize, we need
+// to do some work to add an i32 to it, since things could get wild
+fn calc_next_pointer(pointer: &usize, offset: &i32) -> Option<usize> {
+    if offset.is_negative() {
+        pointer.checked_sub(offset.wrapping_abs() as u32 as usize)
+    } else {
+        pointer.checked_add(*offset as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exits_when_jumping_before_the_first_instruction() {
+        let program: Program = vec![1, -3, 0];
+        assert_eq!(execute_program(program, &add_one_to_offset).unwrap(), 2);
+    }
+
+    #[test]
+    fn reports_invalid_offsets_by_line_and_column() {
+        assert_eq!(
+            parse_input("0\n3\n  +x\n"),
+            Err(ParseError::new(3, 3, "Expected a jump offset, found \"+x\""))
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use crate::error::{tokens_with_columns, ParseError};
+use crate::solution::Solution;
+use std::collections::HashMap;
+
+pub type MemoryBank = u32;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<MemoryBank>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        find_identical_redistribution_cycle(input.clone()).map(|(cycle, _)| cycle)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        find_identical_redistribution_cycle(input.clone()).map(|(_, loop_size)| loop_size)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<MemoryBank>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(index, line)| tokens_with_columns(line).map(move |(column, s)| (index + 1, column, s)))
+        .map(|(line, column, s)| {
+            s.parse()
+                .map_err(|_| ParseError::new(line, column, format!("Expected a block count, found {:?}", s)))
+        })
+        .collect()
+}
+
+// Returns the cycle count where a value repeats for the first time as well as the loop size
+pub fn find_identical_redistribution_cycle(mut memory_banks: Vec<MemoryBank>) -> anyhow::Result<(u32, u32)> {
+    let mut redistribution_cycle_count: u32 = 0;
+    let mut memory_bank_map: HashMap<Vec<u32>, u32> = HashMap::new();
+
+    let first_occurence = loop {
+        redistribution_cycle_count += 1;
+        let max_index = max_memory_bank_index(&memory_banks).ok_or_else(|| anyhow!("There are no memory banks"))?;
+        memory_banks = redistribute(memory_banks.clone(), max_index);
+
+        match memory_bank_map.get(&memory_banks) {
+            Some(first_occurence) => break *first_occurence,
+            None => memory_bank_map.insert(memory_banks.clone(), redistribution_cycle_count),
+        };
+    };
+
+    Ok((redistribution_cycle_count, redistribution_cycle_count - first_occurence))
+}
+
+fn max_memory_bank_index(memory_banks: &[MemoryBank]) -> Option<usize> {
+    let max_element: &u32 = memory_banks.iter().max()?;
+
+    memory_banks.iter().position(|elem| elem == max_element)
+}
+
+fn redistribute(mut memory_banks: Vec<MemoryBank>, mut mem_index: usize) -> Vec<MemoryBank> {
+    let length = memory_banks.len();
+    let mut pool = memory_banks[mem_index];
+    memory_banks[mem_index] = 0;
+
+    while pool > 0 {
+        mem_index = (mem_index + 1) % length;
+        memory_banks[mem_index] += 1;
+        pool -= 1;
+    }
+
+    memory_banks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_memory_bank_index() {
+        let memory_banks: Vec<MemoryBank> = vec![0, 2, 7, 0];
+        assert_eq!(max_memory_bank_index(&memory_banks), Some(2));
+    }
+
+    #[test]
+    fn test_max_memory_bank_index_returns_first_match() {
+        let memory_banks: Vec<MemoryBank> = vec![0, 2, 1, 2];
+        assert_eq!(max_memory_bank_index(&memory_banks), Some(1));
+    }
+
+    #[test]
+    fn test_redistribute() {
+        let memory_banks: Vec<MemoryBank> = vec![0, 2, 7, 0];
+        assert_eq!(redistribute(memory_banks, 2), vec![2, 4, 1, 2]);
+    }
+
+    #[test]
+    fn rejects_invalid_memory_banks() {
+        assert_eq!(
+            parse_input("0\t2\t7\t-1"),
+            Err(ParseError::new(1, 7, "Expected a block count, found \"-1\""))
+        );
+        assert!(find_identical_redistribution_cycle(vec![]).is_err());
+    }
+}
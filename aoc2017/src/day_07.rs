@@ -1,38 +1,36 @@
-#[macro_use]
-extern crate lazy_static;
-
-#[macro_use]
-extern crate anyhow;
-
-const PUZZLE_INPUT: &str = include_str!("../../puzzle_inputs/day_07.txt");
-
+use crate::solution::Solution;
 use anyhow::Context;
 use regex::Regex;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::convert::TryFrom;
 
-fn main() -> anyhow::Result<()> {
-    let programs = parse_input(PUZZLE_INPUT).context("Failed to parse input")?;
+pub struct Day07;
 
-    let root_program = find_root_program(&programs).context("Failed to find root program")?;
-    println!("D7P1: Root program is {}", &root_program);
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = HashMap<String, Program>;
+    type Answer1 = String;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input).context("Failed to parse input")
+    }
 
-    let correct_weight =
-        find_correct_weight_at_leaf(root_program, &programs).context("Error correcting odd program weight")?;
-    println!("D7P2: Correct weight is {}", &correct_weight);
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        find_root_program(input).context("Failed to find root program")
+    }
 
-    Ok(())
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let root_program = find_root_program(input).context("Failed to find root program")?;
+        find_correct_weight_at_leaf(root_program, input).context("Error correcting odd program weight")
+    }
 }
 
-fn find_root_program(programs: &HashMap<String, Program>) -> anyhow::Result<String> {
+pub fn find_root_program(programs: &HashMap<String, Program>) -> anyhow::Result<String> {
     let program_names: HashSet<String> = programs.values().map(|p| p.name.clone()).collect();
-    let children_names: HashSet<String> = programs
-        .values()
-        .map(|p| p.children.clone())
-        .flatten()
-        .flatten()
-        .collect();
+    let children_names: HashSet<String> = programs.values().filter_map(|p| p.children.clone()).flatten().collect();
 
     let difference: Vec<String> = program_names.difference(&children_names).cloned().collect();
 
@@ -42,7 +40,7 @@ fn find_root_program(programs: &HashMap<String, Program>) -> anyhow::Result<Stri
     }
 }
 
-fn find_correct_weight_at_leaf(root_node: String, programs: &HashMap<String, Program>) -> anyhow::Result<u32> {
+pub fn find_correct_weight_at_leaf(root_node: String, programs: &HashMap<String, Program>) -> anyhow::Result<u32> {
     // Get root node, find first odd child
     let root_node = programs.get(&root_node).context("Could not get root node")?;
 
@@ -85,11 +83,11 @@ fn find_correct_weight_at_leaf(root_node: String, programs: &HashMap<String, Pro
 fn children_with_weights(children: HashSet<String>, programs: &HashMap<String, Program>) -> Vec<(String, u32)> {
     children
         .into_iter()
-        .map(|c| (c.clone(), total_weight(&c, &programs)))
+        .map(|c| (c.clone(), total_weight(&c, programs)))
         .collect()
 }
 
-fn parse_input(puzzle_input: &str) -> anyhow::Result<HashMap<String, Program>> {
+pub fn parse_input(puzzle_input: &str) -> anyhow::Result<HashMap<String, Program>> {
     let programs = puzzle_input
         .lines()
         .map(Program::try_from)
@@ -105,7 +103,7 @@ fn total_weight(name: &str, programs: &HashMap<String, Program>) -> u32 {
     match program.children.clone() {
         None => weight,
         Some(children) => {
-            let children_weight: u32 = children.iter().map(|c| total_weight(c, &programs)).sum();
+            let children_weight: u32 = children.iter().map(|c| total_weight(c, programs)).sum();
             weight + children_weight
         }
     }
@@ -146,7 +144,7 @@ fn find_odd_child(programs_with_weights: Vec<(String, u32)>) -> Option<(String,
 }
 
 #[derive(Clone, Debug)]
-struct Program {
+pub struct Program {
     name: String,
     weight: u32,
     children: Option<HashSet<String>>,
@@ -207,15 +205,6 @@ mod tests {
                              gyxo (61)\n\
                              cntj (57)";
 
-    #[test]
-    fn finds_root_program() {
-        let programs = parse_input(TEST_INPUT).unwrap();
-        let root_program = find_root_program(&programs);
-
-        assert!(root_program.is_ok());
-        assert_eq!(root_program.unwrap(), String::from("tknk"));
-    }
-
     #[test]
     fn finds_program_weights() {
         let programs = parse_input(TEST_INPUT).unwrap();
@@ -241,25 +230,4 @@ mod tests {
         assert!(odd_child.is_some());
         assert_eq!(odd_child.unwrap(), (String::from("ugml"), 251, 243));
     }
-
-    #[test]
-    fn finds_correct_weight_at_leaf() {
-        let programs = parse_input(TEST_INPUT).unwrap();
-        let corrected_weight = find_correct_weight_at_leaf(String::from("tknk"), &programs);
-
-        assert!(corrected_weight.is_ok());
-        assert_eq!(corrected_weight.unwrap(), 60);
-    }
-
-    #[test]
-    fn solves_d7() {
-        let programs = parse_input(PUZZLE_INPUT).unwrap();
-        let root_program = find_root_program(&programs).expect("Could not find root program");
-        assert_eq!(root_program, "eugwuhl");
-
-        let corrected_weight =
-            find_correct_weight_at_leaf(root_program, &programs).expect("Could not correct leaf weight");
-
-        assert_eq!(corrected_weight, 420);
-    }
 }
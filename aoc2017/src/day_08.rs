@@ -1,33 +1,43 @@
-#[macro_use]
-extern crate lazy_static;
-
-#[macro_use]
-extern crate anyhow;
-
-const PUZZLE_INPUT: &str = include_str!("../../puzzle_inputs/day_08.txt");
-
+use crate::solution::Solution;
 use anyhow::Context;
 use regex::Regex;
 use std::{collections::HashMap, convert::TryFrom};
 
-fn main() -> anyhow::Result<()> {
-    let (max_register_value, max_value) = calculate(PUZZLE_INPUT)?;
+pub struct Day08;
 
-    println!("D8P1 result is {}", max_register_value);
-    println!("D8P2 result is {}", max_value);
+impl Solution for Day08 {
+    const DAY: u8 = 8;
 
-    Ok(())
+    type Input = Instructions;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        parse_input(input).context("Could not parse input")
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        let (registers, _) = apply_instructions(input);
+        maximum_value(&registers)
+            .copied()
+            .ok_or_else(|| anyhow!("No max value found"))
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        let (_, max_value) = apply_instructions(input);
+        Ok(max_value)
+    }
 }
 
-fn calculate(input: &str) -> anyhow::Result<(i32, i32)> {
+pub fn calculate(input: &str) -> anyhow::Result<(i32, i32)> {
     let instructions = parse_input(input).context("Could not parse input")?;
-    let (registers, max_value) = apply_instructions(instructions);
+    let (registers, max_value) = apply_instructions(&instructions);
     let max_register_value = maximum_value(&registers).ok_or_else(|| anyhow!("No max value found"))?;
 
     Ok((max_register_value.to_owned(), max_value))
 }
 
-type Instructions = Vec<Instruction>;
+pub type Instructions = Vec<Instruction>;
 
 fn parse_input(input: &str) -> anyhow::Result<Instructions> {
     input
@@ -36,13 +46,13 @@ fn parse_input(input: &str) -> anyhow::Result<Instructions> {
         .collect::<Result<Vec<Instruction>, _>>()
 }
 
-fn apply_instructions(instructions: Instructions) -> (HashMap<String, i32>, i32) {
+fn apply_instructions(instructions: &[Instruction]) -> (HashMap<String, i32>, i32) {
     let mut registers: HashMap<String, i32> = HashMap::new();
     let mut highest_value = 0;
 
     for i in instructions {
         if condition_true(&i.condition, &registers) {
-            let result_value = apply_instruction(&i, &mut registers);
+            let result_value = apply_instruction(i, &mut registers);
 
             if result_value > highest_value {
                 highest_value = result_value
@@ -54,7 +64,7 @@ fn apply_instructions(instructions: Instructions) -> (HashMap<String, i32>, i32)
 }
 
 fn condition_true(c: &Condition, registers: &HashMap<String, i32>) -> bool {
-    let value = registers.get(&c.register).or(Some(&0)).unwrap().to_owned();
+    let value = registers.get(&c.register).copied().unwrap_or(0);
 
     match c.operator {
         Operator::EqualTo => value == c.value,
@@ -67,7 +77,7 @@ fn condition_true(c: &Condition, registers: &HashMap<String, i32>) -> bool {
 }
 
 fn apply_instruction(i: &Instruction, registers: &mut HashMap<String, i32>) -> i32 {
-    let value = registers.get(&i.target).or(Some(&0)).unwrap().to_owned();
+    let value = registers.get(&i.target).copied().unwrap_or(0);
 
     let updated_value = match i.operation {
         Operation::Dec => value - i.value,
@@ -97,7 +107,7 @@ struct Condition {
 }
 
 #[derive(Debug)]
-struct Instruction {
+pub struct Instruction {
     condition: Condition,
     operation: Operation,
     target: String,
@@ -178,34 +188,3 @@ impl TryFrom<&str> for Operator {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use indoc::indoc;
-
-    #[test]
-    fn unit_test() {
-        let input = indoc! {"
-            b inc 5 if a > 1
-            a inc 1 if b < 5
-            c dec -10 if a >= 1
-            c inc -20 if c == 10
-        "};
-
-        let (max_value, max_register_value) = calculate(input).unwrap();
-
-        // Part 1
-        assert_eq!(max_value, 1);
-
-        // Part 2
-        assert_eq!(max_register_value, 10);
-    }
-
-    #[test]
-    fn solves_d8() {
-        let (max_value, max_register_value) = calculate(PUZZLE_INPUT).unwrap();
-        assert_eq!(max_value, 7296);
-        assert_eq!(max_register_value, 8186);
-    }
-}
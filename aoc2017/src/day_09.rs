@@ -0,0 +1,90 @@
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input.to_owned())
+    }
+
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1> {
+        Ok(calculate_score(input)?.0)
+    }
+
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2> {
+        Ok(calculate_score(input)?.1)
+    }
+}
+
+pub fn calculate_score(input: &str) -> Result<(u32, u32), ParseError> {
+    let mut score = 0;
+    let mut garbage_start: Option<usize> = None;
+    let mut garbage_chars: u32 = 0;
+    // Offsets of the groups currently open. The nesting level is the number of open groups
+    let mut open_groups: Vec<usize> = Vec::new();
+    let mut stream = input.char_indices();
+
+    while let Some((offset, c)) = stream.next() {
+        if garbage_start.is_some() {
+            match c {
+                '>' => garbage_start = None,
+                '!' => {
+                    stream.next();
+                }
+                _ => garbage_chars += 1,
+            }
+        } else {
+            match c {
+                '<' => garbage_start = Some(offset),
+                '{' => {
+                    open_groups.push(offset);
+                    score += open_groups.len() as u32;
+                }
+                '}' => {
+                    open_groups.pop().ok_or_else(|| {
+                        ParseError::at_offset(input, offset, "Closing a group that was never opened")
+                    })?;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if let Some(offset) = garbage_start {
+        return Err(ParseError::at_offset(input, offset, "Garbage is never closed"));
+    }
+
+    if let Some(offset) = open_groups.pop() {
+        return Err(ParseError::at_offset(input, offset, "Group is never closed"));
+    }
+
+    Ok((score, garbage_chars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_unbalanced_streams() {
+        assert_eq!(
+            calculate_score("{{},{}}}"),
+            Err(ParseError::new(1, 8, "Closing a group that was never opened"))
+        );
+        assert_eq!(
+            calculate_score("{{<a>},\n{<!>}}"),
+            Err(ParseError::new(2, 2, "Garbage is never closed"))
+        );
+        assert_eq!(
+            calculate_score("{{<a>},{}"),
+            Err(ParseError::new(1, 1, "Group is never closed"))
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use std::fmt;
+
+// An error in a puzzle input, pointing at the offending position. Lines and columns start at 1
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    // Locate a byte offset into the input as a line and column
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> ParseError {
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        let column = before[line_start..].chars().count() + 1;
+
+        ParseError::new(line, column, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Split a line into its whitespace separated tokens, along with the column each token starts at
+pub fn tokens_with_columns(line: &str) -> impl Iterator<Item = (usize, &str)> {
+    line.split_whitespace().map(move |token| {
+        let offset = token.as_ptr() as usize - line.as_ptr() as usize;
+        (line[..offset].chars().count() + 1, token)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_offsets() {
+        let input = "12\n3x4\n";
+        assert_eq!(ParseError::at_offset(input, 0, "a"), ParseError::new(1, 1, "a"));
+        assert_eq!(ParseError::at_offset(input, 4, "b"), ParseError::new(2, 2, "b"));
+        assert_eq!(ParseError::at_offset(input, 7, "c"), ParseError::new(3, 1, "c"));
+    }
+
+    #[test]
+    fn formats_position() {
+        let error = ParseError::new(3, 14, "Expected a number");
+        assert_eq!(error.to_string(), "line 3, column 14: Expected a number");
+    }
+
+    #[test]
+    fn finds_token_columns() {
+        let tokens: Vec<(usize, &str)> = tokens_with_columns(" 5 19\tø 8").collect();
+        assert_eq!(tokens, vec![(2, "5"), (4, "19"), (7, "ø"), (9, "8")]);
+    }
+}
@@ -0,0 +1,218 @@
+use crate::input::PUZZLE_INPUTS_DIR;
+use anyhow::Context;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Somewhere puzzle inputs can be downloaded from
+pub trait FetchBackend {
+    fn fetch(&self, day: u8) -> anyhow::Result<String>;
+}
+
+// Fetches inputs over HTTP the way adventofcode.com serves them, authenticated by a session cookie
+pub struct HttpBackend {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl HttpBackend {
+    pub fn new(base_url: &str, session: &str) -> HttpBackend {
+        HttpBackend {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+        }
+    }
+
+    pub fn input_url(&self, day: u8) -> String {
+        format!("{}/2017/day/{}/input", self.base_url, day)
+    }
+}
+
+impl FetchBackend for HttpBackend {
+    fn fetch(&self, day: u8) -> anyhow::Result<String> {
+        let url = self.input_url(day);
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "github.com/skovmand/advent_of_code_2017")
+            .call()
+            .map_err(|e| match e {
+                ureq::Error::Status(status, _) => anyhow!("{} responded with status {}", url, status),
+                e => anyhow!(e).context(format!("Failed to request {}", url)),
+            })?;
+
+        response
+            .into_string()
+            .with_context(|| format!("Failed to read the response from {}", url))
+    }
+}
+
+// Puzzle inputs on disk. The default profile uses `day_NN.txt` in the root, while named
+// profiles keep their inputs in `<profile>/day_NN.txt`
+pub struct InputCache {
+    root: PathBuf,
+}
+
+impl Default for InputCache {
+    fn default() -> Self {
+        InputCache::new(PUZZLE_INPUTS_DIR)
+    }
+}
+
+impl InputCache {
+    pub fn new(root: impl Into<PathBuf>) -> InputCache {
+        InputCache { root: root.into() }
+    }
+
+    pub fn path(&self, profile: Option<&str>, day: u8) -> anyhow::Result<PathBuf> {
+        let file_name = format!("day_{:02}.txt", day);
+
+        match profile {
+            None => Ok(self.root.join(file_name)),
+            Some(profile) => {
+                validate_profile(profile)?;
+                Ok(self.root.join(profile).join(file_name))
+            }
+        }
+    }
+
+    // Read a cached input without touching the network
+    pub fn get(&self, profile: Option<&str>, day: u8) -> anyhow::Result<Option<String>> {
+        let path = self.path(profile, day)?;
+
+        match std::fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(anyhow!(e).context(format!("Failed to read {}", path.display()))),
+        }
+    }
+
+    // Read a cached input, fetching and caching it first if it is missing
+    pub fn get_or_fetch(&self, profile: Option<&str>, day: u8, backend: &dyn FetchBackend) -> anyhow::Result<String> {
+        if let Some(input) = self.get(profile, day)? {
+            return Ok(input);
+        }
+
+        let input = backend
+            .fetch(day)
+            .with_context(|| format!("Failed to fetch the input for day {}", day))?;
+        self.store(&self.path(profile, day)?, &input)?;
+
+        Ok(input)
+    }
+
+    fn store(&self, path: &Path, input: &str) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+
+        // Write next to the destination and rename, so an interrupted fetch never leaves half an input behind
+        let partial = path.with_extension("txt.partial");
+        std::fs::write(&partial, input).with_context(|| format!("Failed to write {}", partial.display()))?;
+        std::fs::rename(&partial, path).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+// Profiles become directory names, so keep them to a single plain path component
+fn validate_profile(profile: &str) -> anyhow::Result<()> {
+    let valid = !profile.is_empty()
+        && !profile.starts_with('.')
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+
+    match valid {
+        true => Ok(()),
+        false => Err(anyhow!(
+            "Invalid profile {:?}. Use letters, digits, '-', '_' and '.'",
+            profile
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    struct CountingBackend {
+        calls: Cell<u32>,
+    }
+
+    impl FetchBackend for CountingBackend {
+        fn fetch(&self, day: u8) -> anyhow::Result<String> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("input for day {}\n", day))
+        }
+    }
+
+    fn temporary_cache(name: &str) -> InputCache {
+        let root = std::env::temp_dir().join(format!("aoc2017-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        InputCache::new(root)
+    }
+
+    #[test]
+    fn resolves_paths_per_profile() {
+        let cache = InputCache::new("inputs");
+        assert_eq!(cache.path(None, 7).unwrap(), Path::new("inputs/day_07.txt"));
+        assert_eq!(
+            cache.path(Some("alice"), 7).unwrap(),
+            Path::new("inputs/alice/day_07.txt")
+        );
+        assert!(cache.path(Some("../bob"), 7).is_err());
+        assert!(cache.path(Some(""), 7).is_err());
+    }
+
+    #[test]
+    fn fetches_missing_inputs_only_once() {
+        let cache = temporary_cache("fetch-once");
+        let backend = CountingBackend { calls: Cell::new(0) };
+
+        assert_eq!(cache.get(Some("alice"), 3).unwrap(), None);
+        assert_eq!(
+            cache.get_or_fetch(Some("alice"), 3, &backend).unwrap(),
+            "input for day 3\n"
+        );
+        assert_eq!(
+            cache.get_or_fetch(Some("alice"), 3, &backend).unwrap(),
+            "input for day 3\n"
+        );
+        assert_eq!(backend.calls.get(), 1);
+        assert_eq!(cache.get(None, 3).unwrap(), None);
+    }
+
+    #[test]
+    fn fetches_over_http_with_the_session_cookie() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        let server = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let reader = BufReader::new(stream.try_clone().unwrap());
+            let request: Vec<String> = reader
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            let body = "0\n3\n0\n1\n-3\n";
+            write!(
+                &stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        let backend = HttpBackend::new(&format!("http://{}/", address), "secret");
+        assert_eq!(backend.fetch(5).unwrap(), "0\n3\n0\n1\n-3\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2017/day/5/input HTTP/1.1");
+        assert!(request.iter().any(|header| header == "Cookie: session=secret"));
+    }
+}
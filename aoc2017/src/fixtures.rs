@@ -0,0 +1,120 @@
+use crate::runner::{self, Part};
+use crate::verify::Expected;
+use anyhow::Context;
+use serde::Deserialize;
+use std::path::Path;
+
+// The examples from the puzzle descriptions, one `day_NN.toml` file per day
+pub const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures");
+
+#[derive(Clone, Debug, Deserialize)]
+struct FixtureFile {
+    #[serde(rename = "example", default)]
+    examples: Vec<Example>,
+}
+
+// An example input with the answer the solver should give for one part
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct Example {
+    // Taken from the name of the fixture file
+    #[serde(skip)]
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub expected: Expected,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExampleOutcome {
+    pub example: Example,
+    // The answer from the solver, or the error it failed with
+    pub actual: Result<String, String>,
+}
+
+impl ExampleOutcome {
+    pub fn is_match(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.example.expected.to_string())
+    }
+}
+
+// Find every `day_NN.toml` fixture file in a directory and load its examples, ordered by day
+pub fn discover(dir: &Path) -> anyhow::Result<Vec<Example>> {
+    let entries = std::fs::read_dir(dir).with_context(|| format!("Failed to read {}", dir.display()))?;
+
+    let mut days = Vec::new();
+    for entry in entries {
+        let file_name = entry?.file_name();
+        if let Some(day) = file_name.to_str().and_then(fixture_day) {
+            days.push(day);
+        }
+    }
+    days.sort_unstable();
+
+    let mut examples = Vec::new();
+    for day in days {
+        examples.extend(load_day(dir, day)?);
+    }
+
+    Ok(examples)
+}
+
+// Load the examples for a single day. A day without a fixture file has no examples
+pub fn load_day(dir: &Path, day: u8) -> anyhow::Result<Vec<Example>> {
+    let path = dir.join(format!("day_{:02}.toml", day));
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let fixtures = std::fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let file: FixtureFile =
+        toml::from_str(&fixtures).with_context(|| format!("Failed to parse fixture file {}", path.display()))?;
+
+    Ok(file
+        .examples
+        .into_iter()
+        .map(|example| Example { day, ..example })
+        .collect())
+}
+
+// Run each example through the solver for its day and part
+pub fn run(examples: &[Example]) -> Vec<ExampleOutcome> {
+    examples
+        .iter()
+        .map(|example| ExampleOutcome {
+            example: example.clone(),
+            actual: runner::solve(example.day, example.part, &example.input)
+                .map(|answer| answer.answer)
+                .map_err(|e| format!("{:#}", e)),
+        })
+        .collect()
+}
+
+fn fixture_day(file_name: &str) -> Option<u8> {
+    file_name.strip_prefix("day_")?.strip_suffix(".toml")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples_give_the_expected_answers() {
+        let examples = discover(Path::new(FIXTURES_DIR)).unwrap();
+        let outcomes = run(&examples);
+        let mismatches: Vec<&ExampleOutcome> = outcomes.iter().filter(|o| !o.is_match()).collect();
+
+        assert!(!outcomes.is_empty());
+        assert!(mismatches.is_empty(), "Mismatching examples: {:#?}", mismatches);
+    }
+
+    #[test]
+    fn takes_the_day_from_the_file_name() {
+        assert_eq!(fixture_day("day_07.toml"), Some(7));
+        assert_eq!(fixture_day("day_07.txt"), None);
+        assert_eq!(fixture_day("README.md"), None);
+
+        let examples = load_day(Path::new(FIXTURES_DIR), 7).unwrap();
+        assert!(examples.iter().all(|example| example.day == 7));
+        assert!(load_day(Path::new(FIXTURES_DIR), 25).unwrap().is_empty());
+    }
+}
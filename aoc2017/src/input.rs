@@ -0,0 +1,91 @@
+use crate::fetch::InputCache;
+use anyhow::Context;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+// The puzzle inputs shipped with the repository
+pub const PUZZLE_INPUTS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../puzzle_inputs");
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum InputSource {
+    #[default]
+    Bundled,
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+    // A cached input fetched for the named profile
+    Profile(String),
+}
+
+impl InputSource {
+    // Interpret a command line path, where `-` means stdin
+    pub fn from_path(path: &str) -> InputSource {
+        match path {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+}
+
+// Read the puzzle input for a day from the given source
+pub fn load(day: u8, source: &InputSource) -> anyhow::Result<String> {
+    match source {
+        InputSource::Bundled => load_bundled(day),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .context("Failed to read puzzle input from stdin")?;
+            Ok(input)
+        }
+        InputSource::Inline(input) => Ok(input.clone()),
+        InputSource::Profile(profile) => InputCache::default().get(Some(profile), day)?.ok_or_else(|| {
+            anyhow!(
+                "No input for day {} is cached for profile {:?}. Run `aoc fetch {} --profile {}` first",
+                day,
+                profile,
+                day,
+                profile
+            )
+        }),
+    }
+}
+
+pub fn load_bundled(day: u8) -> anyhow::Result<String> {
+    read_file(&bundled_path(day))
+}
+
+pub fn bundled_path(day: u8) -> PathBuf {
+    Path::new(PUZZLE_INPUTS_DIR).join(format!("day_{:02}.txt", day))
+}
+
+fn read_file(path: &Path) -> anyhow::Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("Failed to read puzzle input {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loads_bundled_inputs() {
+        assert_eq!(load(3, &InputSource::Bundled).unwrap().trim(), "368078");
+        assert!(load(25, &InputSource::Bundled).is_err());
+    }
+
+    #[test]
+    fn loads_inline_inputs() {
+        let source = InputSource::Inline(String::from("1122"));
+        assert_eq!(load(1, &source).unwrap(), "1122");
+    }
+
+    #[test]
+    fn interprets_dash_as_stdin() {
+        assert_eq!(InputSource::from_path("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_path("in.txt"),
+            InputSource::File(PathBuf::from("in.txt"))
+        );
+    }
+}
@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate lazy_static;
+
+#[macro_use]
+extern crate anyhow;
+
+pub mod bench;
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod error;
+pub mod fetch;
+pub mod fixtures;
+pub mod input;
+pub mod runner;
+pub mod solution;
+#[cfg(test)]
+pub mod testing;
+pub mod verify;
@@ -0,0 +1,135 @@
+use crate::solution::{visit_day, Solution, SolutionVisitor};
+use anyhow::Context;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+pub const DAYS: [u8; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Part {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            part => Err(serde::de::Error::custom(format!("part must be 1 or 2, found {}", part))),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Answer {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub input_hash: String,
+    #[serde(rename = "elapsed_ms", serialize_with = "serialize_millis")]
+    pub elapsed: Duration,
+}
+
+// Run the solver for a single day and part against the given puzzle input
+pub fn solve(day: u8, part: Part, input: &str) -> anyhow::Result<Answer> {
+    let start = Instant::now();
+    let answer = visit_day(day, SolvePart { part, input })
+        .unwrap_or_else(|| Err(anyhow!("Day {} is not solved yet", day)))
+        .with_context(|| format!("Failed to solve day {} part {}", day, part))?;
+    let elapsed = start.elapsed();
+
+    Ok(Answer {
+        day,
+        part,
+        answer,
+        input_hash: input_hash(input),
+        elapsed,
+    })
+}
+
+struct SolvePart<'a> {
+    part: Part,
+    input: &'a str,
+}
+
+impl SolutionVisitor for SolvePart<'_> {
+    type Output = anyhow::Result<String>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let input = S::parse(self.input)?;
+
+        match self.part {
+            Part::One => S::part1(&input).map(|answer| answer.to_string()),
+            Part::Two => S::part2(&input).map(|answer| answer.to_string()),
+        }
+    }
+}
+
+// Hex encoded SHA-256 of the puzzle input, identifying which input an answer belongs to
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn serialize_millis<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::load_bundled;
+
+    #[test]
+    fn solves_a_single_part() {
+        let answer = solve(7, Part::Two, &load_bundled(7).unwrap()).unwrap();
+        assert_eq!((answer.day, answer.part, answer.answer.as_str()), (7, Part::Two, "420"));
+    }
+
+    #[test]
+    fn serializes_answers_as_json() {
+        let answer = solve(1, Part::One, "1122").unwrap();
+        let json = serde_json::to_value(&answer).unwrap();
+
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["part"], 1);
+        assert_eq!(json["answer"], "3");
+        assert_eq!(
+            json["input_hash"],
+            "b3282a2f2a28757b3a18ab833de16a9c54518c0b0cf493e3f0a7cf09386f326a"
+        );
+        assert!(json["elapsed_ms"].is_f64());
+    }
+
+    #[test]
+    fn rejects_unknown_days() {
+        assert!(solve(10, Part::One, "").is_err());
+    }
+}
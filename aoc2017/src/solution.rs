@@ -0,0 +1,63 @@
+use crate::{day_01, day_02, day_03, day_04, day_05, day_06, day_07, day_08, day_09};
+use std::fmt::Display;
+
+// The shape every day is solved in: parse the puzzle input once, then answer both parts from it
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> anyhow::Result<Self::Input>;
+    fn part1(input: &Self::Input) -> anyhow::Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> anyhow::Result<Self::Answer2>;
+}
+
+// Generic tooling implements this to be handed the `Solution` of a day picked at runtime
+pub trait SolutionVisitor {
+    type Output;
+
+    fn visit<S: Solution>(self) -> Self::Output;
+}
+
+// Call the visitor with the solution for the given day, or None if the day is not solved
+pub fn visit_day<V: SolutionVisitor>(day: u8, visitor: V) -> Option<V::Output> {
+    match day {
+        1 => Some(visitor.visit::<day_01::Day01>()),
+        2 => Some(visitor.visit::<day_02::Day02>()),
+        3 => Some(visitor.visit::<day_03::Day03>()),
+        4 => Some(visitor.visit::<day_04::Day04>()),
+        5 => Some(visitor.visit::<day_05::Day05>()),
+        6 => Some(visitor.visit::<day_06::Day06>()),
+        7 => Some(visitor.visit::<day_07::Day07>()),
+        8 => Some(visitor.visit::<day_08::Day08>()),
+        9 => Some(visitor.visit::<day_09::Day09>()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::DAYS;
+
+    struct DayNumber;
+
+    impl SolutionVisitor for DayNumber {
+        type Output = u8;
+
+        fn visit<S: Solution>(self) -> u8 {
+            S::DAY
+        }
+    }
+
+    #[test]
+    fn visits_the_solution_for_each_day() {
+        for day in DAYS.iter() {
+            assert_eq!(visit_day(*day, DayNumber), Some(*day));
+        }
+
+        assert_eq!(visit_day(10, DayNumber), None);
+    }
+}
@@ -0,0 +1,18 @@
+// Helpers shared by the unit tests
+
+// A linear congruential generator, so tests get the same pseudo random values on every run
+pub struct Lcg {
+    state: u32,
+}
+
+impl Lcg {
+    pub fn new(seed: u32) -> Lcg {
+        Lcg { state: seed }
+    }
+
+    // The next value, below `bound`
+    pub fn below(&mut self, bound: u32) -> u32 {
+        self.state = self.state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        (self.state >> 16) % bound
+    }
+}
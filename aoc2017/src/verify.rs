@@ -0,0 +1,141 @@
+use crate::input::PUZZLE_INPUTS_DIR;
+use crate::runner::{self, Part};
+use anyhow::Context;
+use serde::Deserialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+// The registry of known good answers for the bundled puzzle inputs
+pub const DEFAULT_REGISTRY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../puzzle_inputs/answers.toml");
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Registry {
+    #[serde(rename = "answer", default)]
+    pub answers: Vec<ExpectedAnswer>,
+}
+
+// The expected answers for one puzzle input. The input path is relative to the registry file
+#[derive(Clone, Debug, Deserialize)]
+pub struct ExpectedAnswer {
+    pub day: u8,
+    pub input: PathBuf,
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+// Answers can be written as numbers or strings in the registry
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expected::Number(number) => write!(f, "{}", number),
+            Expected::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub expected: String,
+    // The answer from the solver, or the error it failed with
+    pub actual: Result<String, String>,
+}
+
+impl Outcome {
+    pub fn is_match(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected)
+    }
+}
+
+impl Registry {
+    pub fn load(path: &Path) -> anyhow::Result<Registry> {
+        let registry = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+        toml::from_str(&registry).with_context(|| format!("Failed to parse answer registry {}", path.display()))
+    }
+}
+
+// Run every registered input through its solver and compare with the expected answers
+pub fn verify(registry: &Registry, base_dir: &Path) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    for entry in &registry.answers {
+        let input_path = base_dir.join(&entry.input);
+        let input = std::fs::read_to_string(&input_path)
+            .with_context(|| format!("Failed to read puzzle input {}", input_path.display()));
+
+        for (part, expected) in Part::BOTH.iter().zip([&entry.part1, &entry.part2].iter()) {
+            if let Some(expected) = expected {
+                let actual = match &input {
+                    Ok(input) => runner::solve(entry.day, *part, input)
+                        .map(|answer| answer.answer)
+                        .map_err(|e| format!("{:#}", e)),
+                    Err(e) => Err(format!("{:#}", e)),
+                };
+
+                outcomes.push(Outcome {
+                    day: entry.day,
+                    part: *part,
+                    input: entry.input.clone(),
+                    expected: expected.to_string(),
+                    actual,
+                });
+            }
+        }
+    }
+
+    outcomes
+}
+
+// Verify the registry shipped with the bundled puzzle inputs
+pub fn verify_bundled() -> anyhow::Result<Vec<Outcome>> {
+    let registry = Registry::load(Path::new(DEFAULT_REGISTRY))?;
+    Ok(verify(&registry, Path::new(PUZZLE_INPUTS_DIR)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_inputs_give_the_registered_answers() {
+        let outcomes = verify_bundled().unwrap();
+        let mismatches: Vec<&Outcome> = outcomes.iter().filter(|o| !o.is_match()).collect();
+
+        assert_eq!(outcomes.len(), 18);
+        assert!(mismatches.is_empty(), "Mismatching answers: {:#?}", mismatches);
+    }
+
+    #[test]
+    fn reports_mismatches_and_failures() {
+        let registry: Registry = toml::from_str(
+            r#"
+            [[answer]]
+            day = 1
+            input = "day_01.txt"
+            part1 = 42
+
+            [[answer]]
+            day = 4
+            input = "missing.txt"
+            part2 = "186"
+            "#,
+        )
+        .unwrap();
+
+        let outcomes = verify(&registry, Path::new(PUZZLE_INPUTS_DIR));
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].actual, Ok(String::from("997")));
+        assert!(!outcomes[0].is_match());
+        assert!(outcomes[1].actual.is_err());
+    }
+}